@@ -1,7 +1,8 @@
 // Copyright (c) 2025 Vivian Burkhard Voss
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::types::GblnValue;
+use crate::error::{set_last_error, GblnErrorCode};
+use crate::types::{GblnValue, GblnValueType};
 use gbln::Value;
 use std::ffi::CString;
 use std::os::raw::c_char;
@@ -419,3 +420,320 @@ pub extern "C" fn gbln_value_is_null(value: *const GblnValue) -> bool {
 
     matches!(unsafe { (*value).inner() }, Value::Null)
 }
+
+// ============================================================================
+// Reflective Typed Getters/Setters
+// ============================================================================
+//
+// The `gbln_value_as_*()` family above reports "is it this type" through an
+// `ok` out-param per call, which means a binding that doesn't already know a
+// value's type has to probe every variant in turn. `gbln_value_is()` answers
+// that question directly, and the `gbln_value_get_*()`/`gbln_value_set_*()`
+// pairs below route the same pass/fail through the existing `GblnErrorCode`
+// status type instead of a bespoke `bool`, matching how the mutation API in
+// `extensions.rs` reports failure.
+
+/// Check whether a value holds the given type
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+#[no_mangle]
+pub extern "C" fn gbln_value_is(value: *const GblnValue, ty: GblnValueType) -> bool {
+    if value.is_null() {
+        return ty == GblnValueType::Null;
+    }
+
+    GblnValueType::from(unsafe { (*value).inner() }) == ty
+}
+
+/// Get an i32 value
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `out` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_value_get_i32(value: *const GblnValue, out: *mut i32) -> GblnErrorCode {
+    if value.is_null() || out.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    match unsafe { (*value).inner() } {
+        Value::I32(n) => {
+            unsafe {
+                *out = *n;
+            }
+            GblnErrorCode::Ok
+        }
+        _ => {
+            set_last_error("Value is not an i32".to_string(), None);
+            GblnErrorCode::ErrorTypeMismatch
+        }
+    }
+}
+
+/// Set an i32 value, replacing whatever the value previously held
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+#[no_mangle]
+pub extern "C" fn gbln_value_set_i32(value: *mut GblnValue, n: i32) -> GblnErrorCode {
+    set_scalar(value, Value::I32(n))
+}
+
+/// Get a u32 value
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `out` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_value_get_u32(value: *const GblnValue, out: *mut u32) -> GblnErrorCode {
+    if value.is_null() || out.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    match unsafe { (*value).inner() } {
+        Value::U32(n) => {
+            unsafe {
+                *out = *n;
+            }
+            GblnErrorCode::Ok
+        }
+        _ => {
+            set_last_error("Value is not a u32".to_string(), None);
+            GblnErrorCode::ErrorTypeMismatch
+        }
+    }
+}
+
+/// Set a u32 value, replacing whatever the value previously held
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+#[no_mangle]
+pub extern "C" fn gbln_value_set_u32(value: *mut GblnValue, n: u32) -> GblnErrorCode {
+    set_scalar(value, Value::U32(n))
+}
+
+/// Get an i64 value
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `out` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_value_get_i64(value: *const GblnValue, out: *mut i64) -> GblnErrorCode {
+    if value.is_null() || out.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    match unsafe { (*value).inner() } {
+        Value::I64(n) => {
+            unsafe {
+                *out = *n;
+            }
+            GblnErrorCode::Ok
+        }
+        _ => {
+            set_last_error("Value is not an i64".to_string(), None);
+            GblnErrorCode::ErrorTypeMismatch
+        }
+    }
+}
+
+/// Set an i64 value, replacing whatever the value previously held
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+#[no_mangle]
+pub extern "C" fn gbln_value_set_i64(value: *mut GblnValue, n: i64) -> GblnErrorCode {
+    set_scalar(value, Value::I64(n))
+}
+
+/// Get a u64 value
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `out` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_value_get_u64(value: *const GblnValue, out: *mut u64) -> GblnErrorCode {
+    if value.is_null() || out.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    match unsafe { (*value).inner() } {
+        Value::U64(n) => {
+            unsafe {
+                *out = *n;
+            }
+            GblnErrorCode::Ok
+        }
+        _ => {
+            set_last_error("Value is not a u64".to_string(), None);
+            GblnErrorCode::ErrorTypeMismatch
+        }
+    }
+}
+
+/// Set a u64 value, replacing whatever the value previously held
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+#[no_mangle]
+pub extern "C" fn gbln_value_set_u64(value: *mut GblnValue, n: u64) -> GblnErrorCode {
+    set_scalar(value, Value::U64(n))
+}
+
+/// Get an f64 value
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `out` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_value_get_f64(value: *const GblnValue, out: *mut f64) -> GblnErrorCode {
+    if value.is_null() || out.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    match unsafe { (*value).inner() } {
+        Value::F64(n) => {
+            unsafe {
+                *out = *n;
+            }
+            GblnErrorCode::Ok
+        }
+        _ => {
+            set_last_error("Value is not an f64".to_string(), None);
+            GblnErrorCode::ErrorTypeMismatch
+        }
+    }
+}
+
+/// Set an f64 value, replacing whatever the value previously held
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+#[no_mangle]
+pub extern "C" fn gbln_value_set_f64(value: *mut GblnValue, n: f64) -> GblnErrorCode {
+    set_scalar(value, Value::F64(n))
+}
+
+/// Get a bool value
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `out` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_value_get_bool(value: *const GblnValue, out: *mut bool) -> GblnErrorCode {
+    if value.is_null() || out.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    match unsafe { (*value).inner() } {
+        Value::Bool(b) => {
+            unsafe {
+                *out = *b;
+            }
+            GblnErrorCode::Ok
+        }
+        _ => {
+            set_last_error("Value is not a bool".to_string(), None);
+            GblnErrorCode::ErrorTypeMismatch
+        }
+    }
+}
+
+/// Set a bool value, replacing whatever the value previously held
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+#[no_mangle]
+pub extern "C" fn gbln_value_set_bool(value: *mut GblnValue, b: bool) -> GblnErrorCode {
+    set_scalar(value, Value::Bool(b))
+}
+
+/// Borrow a string value as a (pointer, length) pair, tolerating embedded NULs
+///
+/// The returned pointer borrows directly from `value` and is valid only as
+/// long as `value` is; unlike `gbln_value_as_string()`, nothing is copied or
+/// allocated, so there is nothing to free.
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `out_ptr`/`out_len` must be valid pointers to store the result
+#[no_mangle]
+pub extern "C" fn gbln_value_get_str(
+    value: *const GblnValue,
+    out_ptr: *mut *const u8,
+    out_len: *mut usize,
+) -> GblnErrorCode {
+    if value.is_null() || out_ptr.is_null() || out_len.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    match unsafe { (*value).inner() } {
+        Value::Str(s) => {
+            unsafe {
+                *out_ptr = s.as_ptr();
+                *out_len = s.len();
+            }
+            GblnErrorCode::Ok
+        }
+        _ => {
+            set_last_error("Value is not a string".to_string(), None);
+            GblnErrorCode::ErrorTypeMismatch
+        }
+    }
+}
+
+/// Set a string value from a (pointer, length) pair, replacing whatever the
+/// value previously held
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `ptr` must be valid for reads of `len` bytes
+#[no_mangle]
+pub extern "C" fn gbln_value_set_str(value: *mut GblnValue, ptr: *const u8, len: usize) -> GblnErrorCode {
+    if value.is_null() || (ptr.is_null() && len != 0) {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let bytes = if len == 0 {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(ptr, len) }
+    };
+
+    let s = match std::str::from_utf8(bytes) {
+        Ok(s) => s.to_string(),
+        Err(e) => {
+            set_last_error(
+                format!("Invalid UTF-8 at byte offset {}", e.valid_up_to()),
+                None,
+            );
+            return GblnErrorCode::ErrorInvalidSyntax;
+        }
+    };
+
+    set_scalar(value, Value::Str(s))
+}
+
+/// Overwrite `value`'s inner `Value` in place, dropping whatever it held
+fn set_scalar(value: *mut GblnValue, new_value: Value) -> GblnErrorCode {
+    if value.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let inner_ptr = value as *mut Value;
+    unsafe {
+        *inner_ptr = new_value;
+    }
+    GblnErrorCode::Ok
+}
@@ -0,0 +1,324 @@
+// Copyright (c) 2025 Vivian Burkhard Voss
+// SPDX-License-Identifier: Apache-2.0
+
+//! Struct-based, length-prefixed value tree codec
+//!
+//! `gbln_write_io_buf()`/`gbln_to_bytes_with_config()` both return a buffer
+//! as a separate `(*mut u8, usize)` out-param pair, which loses the
+//! allocation's actual capacity and requires two out-params per call. For
+//! bindings that would rather pass one struct around (and potentially reuse
+//! its allocation for a later encode), `GblnBuffer` bundles `data`/`len`/
+//! `capacity` together.
+//!
+//! Unlike `io.rs`/`config.rs`, this is not a GBLN-text encoding: each node
+//! is written as a `GblnValueType` tag byte followed by its payload
+//! (`u32 LE` length prefixes for strings, object field counts, and array
+//! element counts), recursing into children - the same tag/length-prefixed
+//! shape `schema.rs` uses for its flat field table, just deep rather than
+//! flat. That makes the buffer a self-contained binary marshalling
+//! primitive: no text round-trip, no compression, just the value tree.
+
+use crate::error::{set_last_error, GblnErrorCode};
+use crate::types::{GblnValue, GblnValueType};
+use gbln::Value;
+use std::collections::HashMap;
+
+/// A caller-owned byte buffer, with its allocation's true capacity exposed
+///
+/// # Safety
+/// Must be freed with `gbln_value_buffer_free()`, not `gbln_buffer_free()`
+/// or `gbln_bytes_free()` - those free a bare `(ptr, len)` pair with no
+/// `capacity` to recover, which would leak or double-free the tail of this
+/// allocation.
+#[repr(C)]
+pub struct GblnBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+    pub capacity: usize,
+}
+
+/// Write one value node (tag byte + payload) to `out`, recursing into
+/// object/array children depth-first.
+fn encode_value(value: &Value, out: &mut Vec<u8>) {
+    out.push(GblnValueType::from(value) as u8);
+    match value {
+        Value::I8(n) => out.push(*n as u8),
+        Value::I16(n) => out.extend_from_slice(&n.to_le_bytes()),
+        Value::I32(n) => out.extend_from_slice(&n.to_le_bytes()),
+        Value::I64(n) => out.extend_from_slice(&n.to_le_bytes()),
+        Value::U8(n) => out.push(*n),
+        Value::U16(n) => out.extend_from_slice(&n.to_le_bytes()),
+        Value::U32(n) => out.extend_from_slice(&n.to_le_bytes()),
+        Value::U64(n) => out.extend_from_slice(&n.to_le_bytes()),
+        Value::F32(n) => out.extend_from_slice(&n.to_le_bytes()),
+        Value::F64(n) => out.extend_from_slice(&n.to_le_bytes()),
+        Value::Bool(b) => out.push(*b as u8),
+        Value::Null => {}
+        Value::Str(s) => {
+            let bytes = s.as_bytes();
+            out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+            out.extend_from_slice(bytes);
+        }
+        Value::Array(items) => {
+            out.extend_from_slice(&(items.len() as u32).to_le_bytes());
+            for item in items {
+                encode_value(item, out);
+            }
+        }
+        Value::Object(map) => {
+            out.extend_from_slice(&(map.len() as u32).to_le_bytes());
+            for (key, child) in map {
+                let key_bytes = key.as_bytes();
+                out.extend_from_slice(&(key_bytes.len() as u32).to_le_bytes());
+                out.extend_from_slice(key_bytes);
+                encode_value(child, out);
+            }
+        }
+    }
+}
+
+/// Read one value node back out of `data` starting at `*offset`, advancing
+/// `*offset` past it.
+fn decode_value(data: &[u8], offset: &mut usize) -> Result<Value, GblnErrorCode> {
+    fn take<'a>(data: &'a [u8], offset: &mut usize, n: usize) -> Result<&'a [u8], GblnErrorCode> {
+        let end = offset.checked_add(n).ok_or(GblnErrorCode::ErrorUnexpectedEof)?;
+        let slice = data.get(*offset..end).ok_or(GblnErrorCode::ErrorUnexpectedEof)?;
+        *offset = end;
+        Ok(slice)
+    }
+
+    let tag = take(data, offset, 1)?[0];
+    match tag {
+        0 => Ok(Value::I8(take(data, offset, 1)?[0] as i8)),
+        1 => Ok(Value::I16(i16::from_le_bytes(take(data, offset, 2)?.try_into().unwrap()))),
+        2 => Ok(Value::I32(i32::from_le_bytes(take(data, offset, 4)?.try_into().unwrap()))),
+        3 => Ok(Value::I64(i64::from_le_bytes(take(data, offset, 8)?.try_into().unwrap()))),
+        4 => Ok(Value::U8(take(data, offset, 1)?[0])),
+        5 => Ok(Value::U16(u16::from_le_bytes(take(data, offset, 2)?.try_into().unwrap()))),
+        6 => Ok(Value::U32(u32::from_le_bytes(take(data, offset, 4)?.try_into().unwrap()))),
+        7 => Ok(Value::U64(u64::from_le_bytes(take(data, offset, 8)?.try_into().unwrap()))),
+        8 => Ok(Value::F32(f32::from_le_bytes(take(data, offset, 4)?.try_into().unwrap()))),
+        9 => Ok(Value::F64(f64::from_le_bytes(take(data, offset, 8)?.try_into().unwrap()))),
+        10 => {
+            let len = u32::from_le_bytes(take(data, offset, 4)?.try_into().unwrap()) as usize;
+            let bytes = take(data, offset, len)?;
+            let s = std::str::from_utf8(bytes).map_err(|_| GblnErrorCode::ErrorInvalidSyntax)?;
+            Ok(Value::Str(s.to_string()))
+        }
+        11 => Ok(Value::Bool(take(data, offset, 1)?[0] != 0)),
+        12 => Ok(Value::Null),
+        13 => {
+            let count = u32::from_le_bytes(take(data, offset, 4)?.try_into().unwrap());
+            let mut map = HashMap::with_capacity(count as usize);
+            for _ in 0..count {
+                let key_len = u32::from_le_bytes(take(data, offset, 4)?.try_into().unwrap()) as usize;
+                let key_bytes = take(data, offset, key_len)?;
+                let key = std::str::from_utf8(key_bytes)
+                    .map_err(|_| GblnErrorCode::ErrorInvalidSyntax)?
+                    .to_string();
+                map.insert(key, decode_value(data, offset)?);
+            }
+            Ok(Value::Object(map))
+        }
+        14 => {
+            let count = u32::from_le_bytes(take(data, offset, 4)?.try_into().unwrap());
+            let mut items = Vec::with_capacity(count as usize);
+            for _ in 0..count {
+                items.push(decode_value(data, offset)?);
+            }
+            Ok(Value::Array(items))
+        }
+        _ => Err(GblnErrorCode::ErrorInvalidSyntax),
+    }
+}
+
+/// Serialise a value into a `GblnBuffer` as a tagged, length-prefixed binary tree
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `out_buf` must be a valid pointer to store the result
+/// - Caller must free the result with `gbln_value_buffer_free()`
+#[no_mangle]
+pub extern "C" fn gbln_value_to_buffer(
+    value: *const GblnValue,
+    out_buf: *mut GblnBuffer,
+) -> GblnErrorCode {
+    if value.is_null() || out_buf.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let rust_value = unsafe { (*value).inner() };
+    let mut bytes = Vec::new();
+    encode_value(rust_value, &mut bytes);
+
+    let data = bytes.as_mut_ptr();
+    let len = bytes.len();
+    let capacity = bytes.capacity();
+    std::mem::forget(bytes);
+
+    unsafe {
+        *out_buf = GblnBuffer {
+            data,
+            len,
+            capacity,
+        };
+    }
+    GblnErrorCode::Ok
+}
+
+/// Parse a value from a `GblnBuffer` (or any buffer produced by
+/// `gbln_value_to_buffer()`)
+///
+/// # Safety
+/// - `data` must be valid for reads of `len` bytes
+/// - `out_value` must be a valid pointer to store the result
+/// - Caller must free the returned value with `gbln_value_free()`
+#[no_mangle]
+pub extern "C" fn gbln_value_from_buffer(
+    data: *const u8,
+    len: usize,
+    out_value: *mut *mut GblnValue,
+) -> GblnErrorCode {
+    if (data.is_null() && len != 0) || out_value.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let bytes = if len == 0 {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(data, len) }
+    };
+
+    let mut offset = 0;
+    match decode_value(bytes, &mut offset) {
+        Ok(value) => {
+            let boxed = Box::new(GblnValue::new(value));
+            unsafe {
+                *out_value = Box::into_raw(boxed);
+            }
+            GblnErrorCode::Ok
+        }
+        Err(code) => {
+            set_last_error("Malformed GblnBuffer".to_string(), None);
+            code
+        }
+    }
+}
+
+/// Free a `GblnBuffer` returned by `gbln_value_to_buffer()`
+///
+/// # Safety
+/// - `buf` must be a valid pointer to a `GblnBuffer` produced by
+///   `gbln_value_to_buffer()`, or have its fields zeroed
+/// - Must not be called twice on the same buffer
+#[no_mangle]
+pub extern "C" fn gbln_value_buffer_free(buf: *mut GblnBuffer) {
+    if buf.is_null() {
+        return;
+    }
+    unsafe {
+        let b = &*buf;
+        if !b.data.is_null() {
+            drop(Vec::from_raw_parts(b.data, b.len, b.capacity));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(value: Value) -> Value {
+        let mut bytes = Vec::new();
+        encode_value(&value, &mut bytes);
+        let mut offset = 0;
+        let decoded = decode_value(&bytes, &mut offset).expect("decode should succeed");
+        assert_eq!(offset, bytes.len(), "decode should consume the whole buffer");
+        decoded
+    }
+
+    #[test]
+    fn round_trips_scalars() {
+        match round_trip(Value::I64(-1234567890123)) {
+            Value::I64(n) => assert_eq!(n, -1234567890123),
+            other => panic!("expected I64, got {other:?}"),
+        }
+        match round_trip(Value::U8(255)) {
+            Value::U8(n) => assert_eq!(n, 255),
+            other => panic!("expected U8, got {other:?}"),
+        }
+        match round_trip(Value::F64(3.5)) {
+            Value::F64(n) => assert_eq!(n, 3.5),
+            other => panic!("expected F64, got {other:?}"),
+        }
+        match round_trip(Value::Bool(true)) {
+            Value::Bool(b) => assert!(b),
+            other => panic!("expected Bool, got {other:?}"),
+        }
+        assert!(matches!(round_trip(Value::Null), Value::Null));
+    }
+
+    #[test]
+    fn round_trips_string() {
+        match round_trip(Value::Str("hello, world".to_string())) {
+            Value::Str(s) => assert_eq!(s, "hello, world"),
+            other => panic!("expected Str, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn round_trips_nested_array_and_object() {
+        let mut map = HashMap::new();
+        map.insert("list".to_string(), Value::Array(vec![Value::I32(1), Value::I32(2)]));
+        map.insert("name".to_string(), Value::Str("nested".to_string()));
+        let original = Value::Object(map);
+
+        match round_trip(original) {
+            Value::Object(decoded) => {
+                match decoded.get("list") {
+                    Some(Value::Array(items)) => assert_eq!(items.len(), 2),
+                    other => panic!("expected Array, got {other:?}"),
+                }
+                match decoded.get("name") {
+                    Some(Value::Str(s)) => assert_eq!(s, "nested"),
+                    other => panic!("expected Str, got {other:?}"),
+                }
+            }
+            other => panic!("expected Object, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_tag() {
+        let mut offset = 0;
+        let err = decode_value(&[255], &mut offset).unwrap_err();
+        assert_eq!(err, GblnErrorCode::ErrorInvalidSyntax);
+    }
+
+    #[test]
+    fn rejects_truncated_scalar_payload() {
+        // Tag 2 is I32, which needs 4 payload bytes; only one is present.
+        let mut offset = 0;
+        let err = decode_value(&[2, 0], &mut offset).unwrap_err();
+        assert_eq!(err, GblnErrorCode::ErrorUnexpectedEof);
+    }
+
+    #[test]
+    fn rejects_truncated_string_length_prefix() {
+        // Tag 10 is Str; the u32 LE length prefix claims more bytes than exist.
+        let mut offset = 0;
+        let mut data = vec![10u8];
+        data.extend_from_slice(&100u32.to_le_bytes());
+        let err = decode_value(&data, &mut offset).unwrap_err();
+        assert_eq!(err, GblnErrorCode::ErrorUnexpectedEof);
+    }
+
+    #[test]
+    fn rejects_empty_buffer() {
+        let mut offset = 0;
+        let err = decode_value(&[], &mut offset).unwrap_err();
+        assert_eq!(err, GblnErrorCode::ErrorUnexpectedEof);
+    }
+}
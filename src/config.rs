@@ -5,6 +5,9 @@
 //!
 //! Provides C-compatible configuration management for GBLN I/O format.
 
+use crate::error::{set_last_error, GblnErrorCode};
+use crate::io::{decode_auto, encode_with_config};
+use crate::types::GblnValue;
 use gbln::GblnConfig as RustConfig;
 
 /// Opaque wrapper for GblnConfig
@@ -194,3 +197,125 @@ pub extern "C" fn gbln_config_set_strip_comments(config: *mut GblnConfig, value:
         }
     }
 }
+
+// ============================================================================
+// Config-Driven Serialization
+// ============================================================================
+//
+// `gbln_to_string()`/`gbln_to_string_pretty()` ignore `GblnConfig` entirely,
+// so `compress` and `mini_mode` are unreachable from C. These wire the
+// config presets above (`io_format()`/`development()`) through to real
+// encode/decode, sharing the same XZ codec as the buffer-oriented I/O
+// entry points in `io.rs`.
+
+/// Serialise a value to a length-delimited byte buffer per `config`
+///
+/// Unlike `gbln_to_string()`, the result is not a C string: compressed
+/// output is not NUL-safe, so it is returned as an explicit (pointer,
+/// length) pair instead.
+///
+/// Only `mini_mode`, `compress`, and `compression_level` are honored;
+/// `indent`/`strip_comments` are ignored (see `encode_with_config()` in
+/// `io.rs`). Use `gbln_write_io()` if those must be respected.
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `config` may be NULL (uses default `io_format()`)
+/// - `out_buf`/`out_len` must be valid pointers to store the result
+/// - Caller must free the returned buffer with `gbln_bytes_free()`
+#[no_mangle]
+pub extern "C" fn gbln_to_bytes_with_config(
+    value: *const GblnValue,
+    config: *const GblnConfig,
+    out_buf: *mut *mut u8,
+    out_len: *mut usize,
+) -> GblnErrorCode {
+    if value.is_null() || out_buf.is_null() || out_len.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let rust_config = if config.is_null() {
+        RustConfig::io_format()
+    } else {
+        unsafe { (*config).inner.clone() }
+    };
+
+    let rust_value = unsafe { (*value).inner() };
+    let bytes = match encode_with_config(rust_value, &rust_config) {
+        Ok(bytes) => bytes,
+        Err(message) => {
+            set_last_error(message, None);
+            return GblnErrorCode::ErrorIo;
+        }
+    };
+
+    let len = bytes.len();
+    let mut boxed = bytes.into_boxed_slice();
+    let ptr = boxed.as_mut_ptr();
+    std::mem::forget(boxed);
+
+    unsafe {
+        *out_buf = ptr;
+        *out_len = len;
+    }
+    GblnErrorCode::Ok
+}
+
+/// Parse a value from a byte buffer produced by `gbln_to_bytes_with_config()`
+///
+/// Transparently detects and decompresses XZ-compressed input (the same
+/// magic-byte check `gbln_read_io()` uses), so callers do not need to know
+/// which config produced the buffer.
+///
+/// # Safety
+/// - `data` must be valid for reads of `len` bytes
+/// - `out_value` must be a valid pointer to store the result
+/// - Caller must free the returned value with `gbln_value_free()`
+#[no_mangle]
+pub extern "C" fn gbln_from_bytes_with_config(
+    data: *const u8,
+    len: usize,
+    out_value: *mut *mut GblnValue,
+) -> GblnErrorCode {
+    if (data.is_null() && len != 0) || out_value.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let bytes = if len == 0 {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(data, len) }
+    };
+
+    match decode_auto(bytes) {
+        Ok(value) => {
+            let boxed = Box::new(GblnValue::new(value));
+            unsafe {
+                *out_value = Box::into_raw(boxed);
+            }
+            GblnErrorCode::Ok
+        }
+        Err((code, message, suggestion)) => {
+            set_last_error(message, suggestion);
+            code
+        }
+    }
+}
+
+/// Free a buffer returned by `gbln_to_bytes_with_config()`
+///
+/// # Safety
+/// - `ptr`/`len` must be exactly the pair returned from
+///   `gbln_to_bytes_with_config()`
+/// - Must not be called twice on the same buffer
+#[no_mangle]
+pub extern "C" fn gbln_bytes_free(ptr: *mut u8, len: usize) {
+    if ptr.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Box::from_raw(std::slice::from_raw_parts_mut(ptr, len)));
+    }
+}
@@ -0,0 +1,667 @@
+// Copyright (c) 2025 Vivian Burkhard Voss
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resilient, multi-error parsing
+//!
+//! `gbln_parse()` aborts at the first syntax error and only stashes a
+//! single message in the thread-local `LAST_ERROR`. `gbln_parse_all()`
+//! instead keeps going after a syntax error, so editors/linters calling
+//! across the FFI can surface every problem it can find in one pass.
+//!
+//! Recovery is container-aware: when a top-level parse fails and the
+//! failing text opens with `{` or `[`, we locate that container's matching
+//! close and split its body into fields/elements at top-level commas
+//! (string- and nesting-aware), retrying each one independently. A field or
+//! element that still fails to parse contributes one diagnostic and is
+//! dropped; everything else is kept, so one bad field no longer discards
+//! the whole enclosing object. This recurses into object field values and
+//! array elements, so a broken container nested a few levels deep is
+//! isolated rather than taking out its ancestors. Only when the failing
+//! text isn't a recognizable container (stray tokens between root values,
+//! an unterminated container with no matching close) do we fall back to
+//! the coarser line-level resync: skip to the next line at bracket/brace
+//! depth zero and resume from there.
+//!
+//! Caveat: `gbln::Error` does not currently expose the byte offset of the
+//! failing token, only its `ErrorKind`, message and optional suggestion.
+//! For a field/element-level diagnostic, `line`/`column`/`byte_offset`
+//! below describe where *that field, field value or element starts* -
+//! precise enough to jump to the right line, but not the exact failing
+//! character within it. For the line-level fallback, they describe where
+//! the failed parse attempt began (the start of the input, or the point an
+//! earlier resynchronization resumed from).
+
+use crate::error::{map_error_kind, GblnErrorCode};
+use crate::types::GblnValue;
+use gbln::{parse, Value};
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::ptr;
+
+/// A single parse diagnostic produced by `gbln_parse_all()`
+#[repr(C)]
+pub struct GblnDiagnostic {
+    pub code: GblnErrorCode,
+    pub message: *mut c_char,
+    pub suggestion: *mut c_char,
+    pub line: usize,
+    pub column: usize,
+    pub byte_offset: usize,
+}
+
+fn to_c_string_or_null(s: Option<String>) -> *mut c_char {
+    match s {
+        Some(s) => CString::new(s).map(CString::into_raw).unwrap_or(ptr::null_mut()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Find the byte offset to resume parsing from after a failed attempt over
+/// `remaining`: the first newline seen once bracket/brace depth returns to
+/// (or starts at) zero, matching the container that was open when parsing
+/// began, or simply the next newline if nothing was open.
+fn find_recovery_point(remaining: &str) -> Option<usize> {
+    let bytes = remaining.as_bytes();
+    let mut depth: i32 = 0;
+    let mut in_string = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if in_string {
+            if c == b'\\' {
+                i += 2;
+                continue;
+            }
+            if c == b'"' {
+                in_string = false;
+            }
+        } else {
+            match c {
+                b'"' => in_string = true,
+                b'{' | b'[' => depth += 1,
+                b'}' | b']' => depth -= 1,
+                b'\n' if depth <= 0 => return Some(i + 1),
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+
+    None
+}
+
+fn skip_ws(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+fn trim_end_ws(bytes: &[u8], mut i: usize) -> usize {
+    while i > 0 && bytes[i - 1].is_ascii_whitespace() {
+        i -= 1;
+    }
+    i
+}
+
+/// Find the byte offset (inclusive) of the `{`/`[` at `open` that matches
+/// its closer, scanning string- and nesting-aware.
+fn find_matching_close(bytes: &[u8], open: usize) -> Option<usize> {
+    let mut depth: i32 = 0;
+    let mut in_string = false;
+    let mut i = open;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        if in_string {
+            if c == b'\\' {
+                i += 2;
+                continue;
+            }
+            if c == b'"' {
+                in_string = false;
+            }
+        } else {
+            match c {
+                b'"' => in_string = true,
+                b'{' | b'[' => depth += 1,
+                b'}' | b']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i);
+                    }
+                }
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Split `bytes[start..end]` into segments at top-level (depth-0) commas,
+/// string- and nesting-aware. Segments are raw, untrimmed byte ranges.
+fn split_top_level_commas(bytes: &[u8], start: usize, end: usize) -> Vec<(usize, usize)> {
+    let mut segments = Vec::new();
+    let mut depth: i32 = 0;
+    let mut in_string = false;
+    let mut seg_start = start;
+    let mut i = start;
+
+    while i < end {
+        let c = bytes[i];
+        if in_string {
+            if c == b'\\' {
+                i += 2;
+                continue;
+            }
+            if c == b'"' {
+                in_string = false;
+            }
+        } else {
+            match c {
+                b'"' => in_string = true,
+                b'{' | b'[' => depth += 1,
+                b'}' | b']' => depth -= 1,
+                b',' if depth == 0 => {
+                    segments.push((seg_start, i));
+                    seg_start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+    segments.push((seg_start, end));
+    segments
+}
+
+/// Find the top-level (depth-0) `:` separating an object field's key from
+/// its value, string-aware.
+fn find_top_level_colon(bytes: &[u8], start: usize, end: usize) -> Option<usize> {
+    let mut depth: i32 = 0;
+    let mut in_string = false;
+    let mut i = start;
+
+    while i < end {
+        let c = bytes[i];
+        if in_string {
+            if c == b'\\' {
+                i += 2;
+                continue;
+            }
+            if c == b'"' {
+                in_string = false;
+            }
+        } else {
+            match c {
+                b'"' => in_string = true,
+                b'{' | b'[' => depth += 1,
+                b'}' | b']' => depth -= 1,
+                b':' if depth == 0 => return Some(i),
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+fn push_diagnostic(
+    input_str: &str,
+    offset: usize,
+    diagnostics: &mut Vec<GblnDiagnostic>,
+    code: GblnErrorCode,
+    message: String,
+    suggestion: Option<String>,
+) {
+    let (line, column) = line_column_in(input_str, offset);
+    diagnostics.push(GblnDiagnostic {
+        code,
+        message: to_c_string_or_null(Some(message)),
+        suggestion: to_c_string_or_null(suggestion),
+        line,
+        column,
+        byte_offset: offset,
+    });
+}
+
+/// Parse `input_str[start..end]` as a single value, recovering a partial
+/// container if it opens with `{`/`[` and fails whole. Returns `None` (and
+/// has pushed a diagnostic) if nothing could be salvaged.
+fn try_parse_value(
+    input_str: &str,
+    start: usize,
+    end: usize,
+    diagnostics: &mut Vec<GblnDiagnostic>,
+) -> Option<Value> {
+    if start >= end {
+        push_diagnostic(
+            input_str,
+            start,
+            diagnostics,
+            GblnErrorCode::ErrorUnexpectedEof,
+            "Expected a value".to_string(),
+            None,
+        );
+        return None;
+    }
+
+    match parse(&input_str[start..end]) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            let bytes = input_str.as_bytes();
+            let s0 = skip_ws(bytes, start);
+            if s0 < end && (bytes[s0] == b'{' || bytes[s0] == b'[') {
+                if let Some(close) = find_matching_close(bytes, s0) {
+                    if close < end {
+                        return Some(recover_container_value(input_str, s0, close, diagnostics));
+                    }
+                }
+            }
+            push_diagnostic(input_str, start, diagnostics, map_error_kind(&e.kind), e.to_string(), e.suggestion.clone());
+            None
+        }
+    }
+}
+
+/// Recover a single object field (`"key": value`) spanning
+/// `input_str[start..end]`. Tries the field whole first (wrapped in `{}`
+/// so the real parser validates key syntax); on failure, splits at the
+/// top-level colon and recovers the value independently so a broken value
+/// doesn't also discard a syntactically fine key.
+fn recover_object_field(
+    input_str: &str,
+    start: usize,
+    end: usize,
+    diagnostics: &mut Vec<GblnDiagnostic>,
+) -> Option<(String, Value)> {
+    let field_text = &input_str[start..end];
+    if let Ok(Value::Object(map)) = parse(&format!("{{{}}}", field_text)) {
+        return map.into_iter().next();
+    }
+
+    let bytes = input_str.as_bytes();
+    if let Some(colon) = find_top_level_colon(bytes, start, end) {
+        let key_text = input_str[start..colon].trim_end();
+        let value_start = skip_ws(bytes, colon + 1);
+        if let Some(value) = try_parse_value(input_str, value_start, end, diagnostics) {
+            if let Ok(Value::Object(map)) = parse(&format!("{{{}: null}}", key_text)) {
+                if let Some((key, _)) = map.into_iter().next() {
+                    return Some((key, value));
+                }
+            }
+            push_diagnostic(
+                input_str,
+                start,
+                diagnostics,
+                GblnErrorCode::ErrorInvalidSyntax,
+                "Malformed object field key".to_string(),
+                None,
+            );
+        }
+        return None;
+    }
+
+    push_diagnostic(
+        input_str,
+        start,
+        diagnostics,
+        GblnErrorCode::ErrorInvalidSyntax,
+        "Expected \"key\": value".to_string(),
+        None,
+    );
+    None
+}
+
+/// Recover a best-effort `Value` for the container spanning
+/// `input_str[open..=close]` (`open`/`close` are the matching `{`/`}` or
+/// `[`/`]`), by splitting its body at top-level commas and recovering each
+/// field/element independently. A field or element that still fails to
+/// parse contributes one diagnostic and is dropped from the result. An empty
+/// segment is only tolerated silently as a trailing comma (the last segment
+/// before the close); an empty segment anywhere else (e.g. a doubled comma)
+/// still contributes a diagnostic, since it means a value was omitted.
+fn recover_container_value(
+    input_str: &str,
+    open: usize,
+    close: usize,
+    diagnostics: &mut Vec<GblnDiagnostic>,
+) -> Value {
+    let bytes = input_str.as_bytes();
+    let is_object = bytes[open] == b'{';
+    let segments = split_top_level_commas(bytes, open + 1, close);
+    let last_index = segments.len() - 1;
+
+    if is_object {
+        let mut map = HashMap::new();
+        for (i, (seg_start, seg_end)) in segments.into_iter().enumerate() {
+            let s0 = skip_ws(bytes, seg_start);
+            let s1 = trim_end_ws(bytes, seg_end);
+            if s0 >= s1 {
+                if i != last_index {
+                    push_diagnostic(
+                        input_str,
+                        seg_start,
+                        diagnostics,
+                        GblnErrorCode::ErrorUnexpectedEof,
+                        "Expected \"key\": value".to_string(),
+                        None,
+                    );
+                }
+                continue;
+            }
+            if let Some((key, value)) = recover_object_field(input_str, s0, s1, diagnostics) {
+                map.insert(key, value);
+            }
+        }
+        Value::Object(map)
+    } else {
+        let mut items = Vec::new();
+        for (i, (seg_start, seg_end)) in segments.into_iter().enumerate() {
+            let s0 = skip_ws(bytes, seg_start);
+            let s1 = trim_end_ws(bytes, seg_end);
+            if s0 >= s1 {
+                if i != last_index {
+                    push_diagnostic(
+                        input_str,
+                        seg_start,
+                        diagnostics,
+                        GblnErrorCode::ErrorUnexpectedEof,
+                        "Expected a value".to_string(),
+                        None,
+                    );
+                }
+                continue;
+            }
+            if let Some(value) = try_parse_value(input_str, s0, s1, diagnostics) {
+                items.push(value);
+            }
+        }
+        Value::Array(items)
+    }
+}
+
+/// Merge a newly parsed chunk into the accumulated best-effort value.
+///
+/// Two objects merge field-by-field (later chunks win on key collision);
+/// anything else accumulates into an array, since a recovered document is
+/// no longer guaranteed to have a single well-typed root.
+fn merge_into(acc: &mut Option<Value>, value: Value) {
+    match acc.take() {
+        None => *acc = Some(value),
+        Some(Value::Object(mut map)) => {
+            if let Value::Object(new_map) = value {
+                for (k, v) in new_map {
+                    map.insert(k, v);
+                }
+                *acc = Some(Value::Object(map));
+            } else {
+                *acc = Some(Value::Array(vec![Value::Object(map), value]));
+            }
+        }
+        Some(Value::Array(mut items)) => {
+            items.push(value);
+            *acc = Some(Value::Array(items));
+        }
+        Some(other) => {
+            *acc = Some(Value::Array(vec![other, value]));
+        }
+    }
+}
+
+/// Best-effort, multi-error parse
+///
+/// Parses `input`, collecting every syntax error it can recover from
+/// instead of stopping at the first one. For any valid UTF-8 input it
+/// produces a (possibly partial, possibly empty) `GblnValue` alongside the
+/// diagnostics, so editors/linters can show all problems in a single pass.
+///
+/// # Returns
+/// - `GBLN_OK` with `out_value` set to the best-effort parse result and
+///   `out_diags`/`out_diag_count` set to every diagnostic found (may be
+///   zero if the input parsed cleanly)
+/// - `GBLN_ERROR_NULL_POINTER` if `input`/`out_value`/`out_diags`/
+///   `out_diag_count` themselves are NULL
+/// - `GBLN_ERROR_INVALID_SYNTAX` if `input` is not valid UTF-8, with
+///   `*out_value`/`*out_diags` set to NULL and `*out_diag_count` to zero
+///
+/// # Safety
+/// - `input` must be a valid null-terminated UTF-8 string
+/// - `out_value`, `out_diags`, `out_diag_count` must be valid pointers
+/// - Caller must free `*out_value` with `gbln_value_free()` and the
+///   diagnostics array with `gbln_diagnostics_free()`
+#[no_mangle]
+pub extern "C" fn gbln_parse_all(
+    input: *const c_char,
+    out_value: *mut *mut GblnValue,
+    out_diags: *mut *mut GblnDiagnostic,
+    out_diag_count: *mut usize,
+) -> GblnErrorCode {
+    if input.is_null() || out_value.is_null() || out_diags.is_null() || out_diag_count.is_null() {
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let input_str = match unsafe { CStr::from_ptr(input).to_str() } {
+        Ok(s) => s,
+        Err(_) => {
+            unsafe {
+                *out_value = ptr::null_mut();
+                *out_diags = ptr::null_mut();
+                *out_diag_count = 0;
+            }
+            return GblnErrorCode::ErrorInvalidSyntax;
+        }
+    };
+
+    let bytes = input_str.as_bytes();
+    let mut pos = 0usize;
+    let mut diagnostics = Vec::new();
+    let mut merged: Option<Value> = None;
+
+    // Bound the recovery loop: every successful resync consumes at least
+    // one line, so this is a backstop against pathological input, not a
+    // cap on how many real diagnostics can be reported.
+    let mut guard = 0usize;
+    let guard_limit = input_str.len() + 1;
+
+    loop {
+        guard += 1;
+        pos = skip_ws(bytes, pos);
+        if guard > guard_limit || pos >= bytes.len() {
+            break;
+        }
+
+        match parse(&input_str[pos..]) {
+            Ok(value) => {
+                merge_into(&mut merged, value);
+                break;
+            }
+            Err(e) => {
+                if bytes[pos] == b'{' || bytes[pos] == b'[' {
+                    if let Some(close) = find_matching_close(bytes, pos) {
+                        let value = recover_container_value(input_str, pos, close, &mut diagnostics);
+                        merge_into(&mut merged, value);
+                        pos = close + 1;
+                        continue;
+                    }
+                }
+
+                push_diagnostic(input_str, pos, &mut diagnostics, map_error_kind(&e.kind), e.to_string(), e.suggestion.clone());
+
+                match find_recovery_point(&input_str[pos..]) {
+                    Some(advance) if advance > 0 => pos += advance,
+                    _ => break,
+                }
+            }
+        }
+    }
+
+    let value = merged.unwrap_or_else(|| Value::Object(HashMap::new()));
+    unsafe {
+        *out_value = Box::into_raw(Box::new(GblnValue::new(value)));
+    }
+
+    let count = diagnostics.len();
+    if count == 0 {
+        unsafe {
+            *out_diags = ptr::null_mut();
+            *out_diag_count = 0;
+        }
+    } else {
+        let mut boxed = diagnostics.into_boxed_slice();
+        let ptr = boxed.as_mut_ptr();
+        std::mem::forget(boxed);
+        unsafe {
+            *out_diags = ptr;
+            *out_diag_count = count;
+        }
+    }
+
+    GblnErrorCode::Ok
+}
+
+/// Compute the 1-based (line, column) of `offset` within `text`.
+fn line_column_in(text: &str, offset: usize) -> (usize, usize) {
+    let mut line = 1usize;
+    let mut column = 1usize;
+    for (i, ch) in text.char_indices() {
+        if i >= offset {
+            break;
+        }
+        if ch == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+    (line, column)
+}
+
+/// Free the diagnostics array returned by `gbln_parse_all()`
+///
+/// # Safety
+/// - `diags`/`count` must be exactly the pair returned from the same
+///   `gbln_parse_all()` call
+/// - Must not be called twice on the same array
+#[no_mangle]
+pub extern "C" fn gbln_diagnostics_free(diags: *mut GblnDiagnostic, count: usize) {
+    if diags.is_null() || count == 0 {
+        return;
+    }
+
+    unsafe {
+        let slice = std::slice::from_raw_parts_mut(diags, count);
+        for diag in slice.iter_mut() {
+            if !diag.message.is_null() {
+                drop(CString::from_raw(diag.message));
+            }
+            if !diag.suggestion.is_null() {
+                drop(CString::from_raw(diag.suggestion));
+            }
+        }
+        drop(Box::from_raw(slice));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    #[test]
+    fn find_matching_close_handles_nesting_and_strings() {
+        let text = br#"{"a": [1, "}", {"b": 2}]}"#;
+        assert_eq!(find_matching_close(text, 0), Some(text.len() - 1));
+    }
+
+    #[test]
+    fn find_matching_close_returns_none_when_unterminated() {
+        let text = br#"{"a": 1"#;
+        assert_eq!(find_matching_close(text, 0), None);
+    }
+
+    #[test]
+    fn split_top_level_commas_ignores_nested_and_string_commas() {
+        let text = br#"1, "a,b", [2, 3]"#;
+        let segments = split_top_level_commas(text, 0, text.len());
+        let spans: Vec<&str> = segments
+            .iter()
+            .map(|(s, e)| std::str::from_utf8(&text[*s..*e]).unwrap())
+            .collect();
+        assert_eq!(spans, vec!["1", " \"a,b\"", " [2, 3]"]);
+    }
+
+    #[test]
+    fn find_top_level_colon_skips_nested_and_string_colons() {
+        let text = br#""a:b": {"c": 1}"#;
+        let colon = find_top_level_colon(text, 0, text.len()).unwrap();
+        assert_eq!(&text[colon..colon + 1], b":");
+        assert_eq!(&text[..colon], br#""a:b""#);
+    }
+
+    fn parse_all(input: &str) -> (Value, usize) {
+        let c_input = CString::new(input).unwrap();
+        let mut out_value: *mut GblnValue = ptr::null_mut();
+        let mut out_diags: *mut GblnDiagnostic = ptr::null_mut();
+        let mut out_diag_count: usize = 0;
+        let code = gbln_parse_all(
+            c_input.as_ptr(),
+            &mut out_value,
+            &mut out_diags,
+            &mut out_diag_count,
+        );
+        assert_eq!(code, GblnErrorCode::Ok);
+
+        let value = unsafe { Box::from_raw(out_value) }.into_inner();
+        let diag_count = out_diag_count;
+        gbln_diagnostics_free(out_diags, out_diag_count);
+        (value, diag_count)
+    }
+
+    #[test]
+    fn recovers_one_bad_field_without_losing_the_rest_of_the_object() {
+        let (value, diag_count) =
+            parse_all(r#"{"good": "a", "bad": , "also_good": "b"}"#);
+        assert_eq!(diag_count, 1);
+        match value {
+            Value::Object(map) => {
+                assert!(matches!(map.get("good"), Some(Value::Str(s)) if s == "a"));
+                assert!(matches!(map.get("also_good"), Some(Value::Str(s)) if s == "b"));
+                assert!(!map.contains_key("bad"));
+            }
+            other => panic!("expected Object, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn recovers_one_bad_element_without_losing_the_rest_of_the_array() {
+        let (value, diag_count) = parse_all(r#"["a", , "c"]"#);
+        assert_eq!(diag_count, 1);
+        match value {
+            Value::Array(items) => assert_eq!(items.len(), 2),
+            other => panic!("expected Array, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn tolerates_a_trailing_comma_without_a_diagnostic() {
+        let (value, diag_count) = parse_all(r#"["a", "b", ]"#);
+        assert_eq!(diag_count, 0);
+        match value {
+            Value::Array(items) => assert_eq!(items.len(), 2),
+            other => panic!("expected Array, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn clean_input_produces_no_diagnostics() {
+        let (value, diag_count) = parse_all(r#"{"a": "b"}"#);
+        assert_eq!(diag_count, 0);
+        assert!(matches!(value, Value::Object(_)));
+    }
+}
@@ -1,5 +1,8 @@
 use gbln::ErrorKind;
 use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
 
 /// C-compatible error codes
 ///
@@ -20,6 +23,7 @@ pub enum GblnErrorCode {
     ErrorDuplicateKey = 10,
     ErrorNullPointer = 11,
     ErrorIo = 12,
+    ErrorAllocation = 13,
 }
 
 // Thread-local error storage
@@ -42,6 +46,105 @@ pub fn get_last_error() -> Option<(String, Option<String>)> {
     LAST_ERROR.with(|e| e.borrow().clone())
 }
 
+// ============================================================================
+// Structured, Thread-Independent Errors
+// ============================================================================
+//
+// `LAST_ERROR` is thread-local: an error set on a worker thread is invisible
+// to whichever thread later calls `gbln_last_error_message()`, which breaks
+// down once a host dispatches GBLN calls onto a thread pool. `GblnError` is
+// an owned, self-contained alternative that a fallible `_ex` entry point can
+// hand back directly, independent of which thread retrieves it.
+
+/// A structured, caller-owned error, independent of the thread-local slot
+///
+/// Caveat: `gbln::Error` does not currently expose the byte offset of the
+/// failing token (see the same caveat in `diagnostics.rs`), so until that
+/// lands upstream, `line`/`column`/`byte_offset` are always `1`/`1`/`0` -
+/// present in the ABI for forward compatibility, not yet load-bearing.
+#[repr(C)]
+pub struct GblnError {
+    pub code: GblnErrorCode,
+    pub message: *mut c_char,
+    pub suggestion: *mut c_char,
+    pub line: usize,
+    pub column: usize,
+    pub byte_offset: usize,
+}
+
+/// Build a `GblnError` from its parts, used by `_ex` entry points
+pub(crate) fn make_gbln_error(
+    code: GblnErrorCode,
+    message: String,
+    suggestion: Option<String>,
+    line: usize,
+    column: usize,
+    byte_offset: usize,
+) -> *mut GblnError {
+    let message_ptr = CString::new(message)
+        .map(CString::into_raw)
+        .unwrap_or(ptr::null_mut());
+    let suggestion_ptr = suggestion
+        .and_then(|s| CString::new(s).ok())
+        .map(CString::into_raw)
+        .unwrap_or(ptr::null_mut());
+
+    Box::into_raw(Box::new(GblnError {
+        code,
+        message: message_ptr,
+        suggestion: suggestion_ptr,
+        line,
+        column,
+        byte_offset,
+    }))
+}
+
+/// Report an error both ways: into the thread-local slot (for backward
+/// compatibility with `gbln_last_error_message()`) and, if `out_error` is
+/// non-NULL, into a freshly allocated `GblnError` the caller owns.
+///
+/// `line`/`column`/`byte_offset` describe the failing position when known;
+/// pass `(1, 1, 0)` when `gbln::Error` gives no position (see the caveat on
+/// `GblnError`).
+pub(crate) fn report_error(
+    out_error: *mut *mut GblnError,
+    code: GblnErrorCode,
+    message: String,
+    suggestion: Option<String>,
+    line: usize,
+    column: usize,
+    byte_offset: usize,
+) -> GblnErrorCode {
+    set_last_error(message.clone(), suggestion.clone());
+    if !out_error.is_null() {
+        unsafe {
+            *out_error = make_gbln_error(code, message, suggestion, line, column, byte_offset);
+        }
+    }
+    code
+}
+
+/// Free a `GblnError` returned by an `_ex` entry point
+///
+/// # Safety
+/// - `error` must be a valid pointer returned from a `_ex` entry point or NULL
+/// - Must not be called twice on the same pointer
+#[no_mangle]
+pub extern "C" fn gbln_error_free(error: *mut GblnError) {
+    if error.is_null() {
+        return;
+    }
+    unsafe {
+        let boxed = Box::from_raw(error);
+        if !boxed.message.is_null() {
+            drop(CString::from_raw(boxed.message));
+        }
+        if !boxed.suggestion.is_null() {
+            drop(CString::from_raw(boxed.suggestion));
+        }
+    }
+}
+
 /// Map Rust ErrorKind to C error code
 pub fn map_error_kind(kind: &ErrorKind) -> GblnErrorCode {
     match kind {
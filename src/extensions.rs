@@ -11,11 +11,31 @@
 use crate::error::{set_last_error, GblnErrorCode};
 use crate::types::{GblnValue, GblnValueType};
 use gbln::Value;
+use std::alloc::Layout;
 use std::collections::HashMap;
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use std::ptr;
 
+/// Allocate a `GblnValue` without letting the global allocator abort the
+/// process on OOM.
+///
+/// `Box::new` goes through the infallible `GlobalAlloc` path, which calls
+/// `handle_alloc_error` (process abort) when the allocation fails. Across an
+/// FFI boundary the host may want to recover instead, so this allocates with
+/// raw `std::alloc::alloc` and reports failure as `None`.
+fn try_box_value(value: Value) -> Option<Box<GblnValue>> {
+    let layout = Layout::new::<GblnValue>();
+    let ptr = unsafe { std::alloc::alloc(layout) as *mut GblnValue };
+    if ptr.is_null() {
+        return None;
+    }
+    unsafe {
+        ptr.write(GblnValue::new(value));
+        Some(Box::from_raw(ptr))
+    }
+}
+
 // ============================================================================
 // Type Introspection
 // ============================================================================
@@ -111,6 +131,117 @@ pub extern "C" fn gbln_object_keys(
     }
 }
 
+/// Get object keys as counted (pointer, length) pairs
+///
+/// Unlike `gbln_object_keys()`, the returned keys are not null-terminated
+/// C strings: each key is a raw byte buffer whose length is reported
+/// alongside it, so callers never need to `strlen()` and keys containing
+/// interior NUL bytes (which `gbln_object_keys()` would silently truncate
+/// at) round-trip intact.
+///
+/// Both arrays are produced from a single pass over the object so that
+/// `keys[i]`/`lengths[i]` refer to the same field even though `HashMap`
+/// iteration order is not guaranteed to repeat across separate calls.
+///
+/// Returns NULL if value is not an object.
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `out_lengths` must be a valid pointer to store the lengths array
+/// - `out_count` must be a valid pointer to store the count
+/// - Caller must free both returned arrays with `gbln_keys_n_free()`
+#[no_mangle]
+pub extern "C" fn gbln_object_keys_n(
+    value: *const GblnValue,
+    out_lengths: *mut *mut usize,
+    out_count: *mut usize,
+) -> *mut *mut u8 {
+    if value.is_null() || out_lengths.is_null() || out_count.is_null() {
+        return ptr::null_mut();
+    }
+
+    match unsafe { (*value).inner() } {
+        Value::Object(map) => {
+            let mut keys: Vec<*mut u8> = Vec::with_capacity(map.len());
+            let mut lengths: Vec<usize> = Vec::with_capacity(map.len());
+
+            for key in map.keys() {
+                let bytes = key.as_bytes();
+                let mut owned = bytes.to_vec().into_boxed_slice();
+                let ptr = owned.as_mut_ptr();
+                std::mem::forget(owned);
+                keys.push(ptr);
+                lengths.push(bytes.len());
+            }
+
+            let count = keys.len();
+            unsafe {
+                *out_count = count;
+            }
+
+            if count == 0 {
+                unsafe {
+                    *out_lengths = ptr::null_mut();
+                }
+                return ptr::null_mut();
+            }
+
+            let mut lengths_boxed = lengths.into_boxed_slice();
+            let lengths_ptr = lengths_boxed.as_mut_ptr();
+            std::mem::forget(lengths_boxed);
+            unsafe {
+                *out_lengths = lengths_ptr;
+            }
+
+            let mut keys_boxed = keys.into_boxed_slice();
+            let keys_ptr = keys_boxed.as_mut_ptr();
+            std::mem::forget(keys_boxed);
+            keys_ptr
+        }
+        _ => {
+            unsafe {
+                *out_count = 0;
+                *out_lengths = ptr::null_mut();
+            }
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Free arrays returned by `gbln_object_keys_n()`
+///
+/// # Safety
+/// - `keys` and `lengths` must be the arrays returned together from the
+///   same `gbln_object_keys_n()` call, with `count` the count it returned
+/// - Must not be called twice on the same pair of pointers
+#[no_mangle]
+pub extern "C" fn gbln_keys_n_free(keys: *mut *mut u8, lengths: *mut usize, count: usize) {
+    if count == 0 {
+        return;
+    }
+
+    unsafe {
+        if !keys.is_null() {
+            for i in 0..count {
+                let key_ptr = *keys.add(i);
+                let key_len = if lengths.is_null() { 0 } else { *lengths.add(i) };
+                if !key_ptr.is_null() {
+                    drop(Box::from_raw(std::slice::from_raw_parts_mut(
+                        key_ptr, key_len,
+                    )));
+                }
+            }
+            drop(Box::from_raw(std::slice::from_raw_parts_mut(keys, count)));
+        }
+
+        if !lengths.is_null() {
+            drop(Box::from_raw(std::slice::from_raw_parts_mut(
+                lengths, count,
+            )));
+        }
+    }
+}
+
 /// Free keys array
 ///
 /// Frees array returned by `gbln_object_keys()`.
@@ -256,6 +387,66 @@ pub extern "C" fn gbln_value_new_str(value: *const c_char, max_len: usize) -> *m
     Box::into_raw(Box::new(GblnValue::new(Value::Str(value_str.to_string()))))
 }
 
+/// Create string value from a counted, non-null-terminated buffer
+///
+/// Unlike `gbln_value_new_str()`, this does not scan for a NUL terminator:
+/// the string is built directly from `ptr[0..len]`, so embedded NUL bytes
+/// are preserved rather than silently truncating the value at the first one.
+///
+/// # Args
+/// - ptr: start of a UTF-8 byte buffer (need not be null-terminated)
+/// - len: number of bytes at `ptr`
+/// - max_len: maximum string length in characters (for type hint)
+///
+/// # Returns
+/// - GblnValue pointer on success
+/// - NULL if string exceeds max_len or the buffer is not valid UTF-8
+///
+/// # Safety
+/// - `ptr` must be valid for reads of `len` bytes
+#[no_mangle]
+pub extern "C" fn gbln_value_new_str_n(
+    ptr: *const u8,
+    len: usize,
+    max_len: usize,
+) -> *mut GblnValue {
+    if ptr.is_null() && len != 0 {
+        set_last_error("Null pointer for string value".to_string(), None);
+        return ptr::null_mut();
+    }
+
+    let bytes = if len == 0 {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(ptr, len) }
+    };
+
+    let value_str = match std::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(e) => {
+            set_last_error(
+                format!("Invalid UTF-8 at byte offset {}", e.valid_up_to()),
+                None,
+            );
+            return ptr::null_mut();
+        }
+    };
+
+    let char_count = value_str.chars().count();
+    if char_count > max_len {
+        set_last_error(
+            format!("String too long: {} chars (max: {})", char_count, max_len),
+            Some(format!(
+                "Use a larger string type (s{} or larger)",
+                max_len * 2
+            )),
+        );
+        return ptr::null_mut();
+    }
+
+    Box::into_raw(Box::new(GblnValue::new(Value::Str(value_str.to_string()))))
+}
+
 /// Create boolean value
 #[no_mangle]
 pub extern "C" fn gbln_value_new_bool(value: bool) -> *mut GblnValue {
@@ -311,6 +502,65 @@ pub extern "C" fn gbln_object_insert(
         }
     };
 
+    object_insert_owned(object, key_str, value)
+}
+
+/// Insert field into object using a counted, non-null-terminated key buffer
+///
+/// Behaves exactly like `gbln_object_insert()`, except the key is read from
+/// `key_ptr[0..key_len]` directly (no `strlen`), so keys containing interior
+/// NUL bytes round-trip intact instead of silently truncating.
+///
+/// # Safety
+/// - `object` must be a GblnValue of type Object
+/// - `key_ptr` must be valid for reads of `key_len` bytes
+/// - `value` ownership is transferred to the object
+///
+/// # Returns
+/// - GBLN_OK on success
+/// - GBLN_ERROR_DUPLICATE_KEY if key already exists
+/// - GBLN_ERROR_TYPE_MISMATCH if object is not an Object type
+/// - GBLN_ERROR_NULL_POINTER if any pointer is null
+/// - GBLN_ERROR_INVALID_SYNTAX if the key is not valid UTF-8
+#[no_mangle]
+pub extern "C" fn gbln_object_insert_n(
+    object: *mut GblnValue,
+    key_ptr: *const u8,
+    key_len: usize,
+    value: *mut GblnValue,
+) -> GblnErrorCode {
+    if object.is_null() || value.is_null() || (key_ptr.is_null() && key_len != 0) {
+        set_last_error("Null pointer in object_insert_n".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let key_bytes = if key_len == 0 {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(key_ptr, key_len) }
+    };
+
+    let key_str = match std::str::from_utf8(key_bytes) {
+        Ok(s) => s.to_string(),
+        Err(e) => {
+            set_last_error(
+                format!("Invalid UTF-8 in key at byte offset {}", e.valid_up_to()),
+                None,
+            );
+            return GblnErrorCode::ErrorInvalidSyntax;
+        }
+    };
+
+    object_insert_owned(object, key_str, value)
+}
+
+/// Shared insert logic for `gbln_object_insert()`/`gbln_object_insert_n()`
+/// once the key has been decoded into an owned `String`.
+fn object_insert_owned(
+    object: *mut GblnValue,
+    key_str: String,
+    value: *mut GblnValue,
+) -> GblnErrorCode {
     // Take ownership of value
     let value_box = unsafe { Box::from_raw(value) };
     let value_inner = value_box.into_inner();
@@ -334,6 +584,14 @@ pub extern "C" fn gbln_object_insert(
                     return GblnErrorCode::ErrorDuplicateKey;
                 }
 
+                if map.try_reserve(1).is_err() {
+                    set_last_error(
+                        "Failed to reserve capacity for object field".to_string(),
+                        None,
+                    );
+                    return GblnErrorCode::ErrorAllocation;
+                }
+
                 map.insert(key_str, value_inner);
                 GblnErrorCode::Ok
             } else {
@@ -391,6 +649,14 @@ pub extern "C" fn gbln_array_push(array: *mut GblnValue, value: *mut GblnValue)
             let inner_ref = unsafe { &mut *inner_ptr };
 
             if let Value::Array(ref mut vec) = inner_ref {
+                if vec.try_reserve(1).is_err() {
+                    set_last_error(
+                        "Failed to reserve capacity for array element".to_string(),
+                        None,
+                    );
+                    return GblnErrorCode::ErrorAllocation;
+                }
+
                 vec.push(value_inner);
                 GblnErrorCode::Ok
             } else {
@@ -406,3 +672,624 @@ pub extern "C" fn gbln_array_push(array: *mut GblnValue, value: *mut GblnValue)
         }
     }
 }
+
+// ============================================================================
+// Fallible-Allocation Constructors
+// ============================================================================
+//
+// The plain `gbln_value_new_*` family allocates through `Box::new`, which
+// aborts the process on OOM. The `_checked` variants below allocate through
+// `try_box_value()` instead, handing the value back through `out_value` and
+// returning `ErrorAllocation` rather than aborting. Prefer these when embedding GBLN in a host that must
+// survive allocation failure (an embedded runtime, a sandbox, a long-lived
+// server).
+
+// The eleven scalar constructors below differ only in the `Value` variant
+// they wrap, so they're generated from one macro rather than pasted by hand;
+// `_null`/`_str`/`_object`/`_array` stay hand-written below since their
+// bodies (no-arg constructors, length checks) aren't just variant swaps.
+macro_rules! checked_scalar_constructor {
+    ($(#[$doc:meta])* $fn_name:ident, $param_ty:ty, $variant:ident) => {
+        $(#[$doc])*
+        ///
+        /// # Safety
+        /// - `out_value` must be a valid pointer to store the result
+        #[no_mangle]
+        pub extern "C" fn $fn_name(
+            value: $param_ty,
+            out_value: *mut *mut GblnValue,
+        ) -> GblnErrorCode {
+            if out_value.is_null() {
+                set_last_error("Null out_value pointer".to_string(), None);
+                return GblnErrorCode::ErrorNullPointer;
+            }
+
+            match try_box_value(Value::$variant(value)) {
+                Some(boxed) => {
+                    unsafe {
+                        *out_value = Box::into_raw(boxed);
+                    }
+                    GblnErrorCode::Ok
+                }
+                None => {
+                    set_last_error("Allocation failed".to_string(), None);
+                    GblnErrorCode::ErrorAllocation
+                }
+            }
+        }
+    };
+}
+
+checked_scalar_constructor!(
+    /// Create i8 value, reporting allocation failure instead of aborting
+    gbln_value_new_i8_checked, i8, I8
+);
+
+checked_scalar_constructor!(
+    /// Create i16 value, reporting allocation failure instead of aborting
+    gbln_value_new_i16_checked, i16, I16
+);
+
+checked_scalar_constructor!(
+    /// Create i32 value, reporting allocation failure instead of aborting
+    gbln_value_new_i32_checked, i32, I32
+);
+
+checked_scalar_constructor!(
+    /// Create i64 value, reporting allocation failure instead of aborting
+    gbln_value_new_i64_checked, i64, I64
+);
+
+checked_scalar_constructor!(
+    /// Create u8 value, reporting allocation failure instead of aborting
+    gbln_value_new_u8_checked, u8, U8
+);
+
+checked_scalar_constructor!(
+    /// Create u16 value, reporting allocation failure instead of aborting
+    gbln_value_new_u16_checked, u16, U16
+);
+
+checked_scalar_constructor!(
+    /// Create u32 value, reporting allocation failure instead of aborting
+    gbln_value_new_u32_checked, u32, U32
+);
+
+checked_scalar_constructor!(
+    /// Create u64 value, reporting allocation failure instead of aborting
+    gbln_value_new_u64_checked, u64, U64
+);
+
+checked_scalar_constructor!(
+    /// Create f32 value, reporting allocation failure instead of aborting
+    gbln_value_new_f32_checked, f32, F32
+);
+
+checked_scalar_constructor!(
+    /// Create f64 value, reporting allocation failure instead of aborting
+    gbln_value_new_f64_checked, f64, F64
+);
+
+checked_scalar_constructor!(
+    /// Create boolean value, reporting allocation failure instead of aborting
+    gbln_value_new_bool_checked, bool, Bool
+);
+
+/// Create null value, reporting allocation failure instead of aborting
+///
+/// # Safety
+/// - `out_value` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_value_new_null_checked(out_value: *mut *mut GblnValue) -> GblnErrorCode {
+    if out_value.is_null() {
+        set_last_error("Null out_value pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    match try_box_value(Value::Null) {
+        Some(boxed) => {
+            unsafe {
+                *out_value = Box::into_raw(boxed);
+            }
+            GblnErrorCode::Ok
+        }
+        None => {
+            set_last_error("Allocation failed".to_string(), None);
+            GblnErrorCode::ErrorAllocation
+        }
+    }
+}
+
+/// Create string value, reporting allocation failure instead of aborting
+///
+/// Applies the same length check as `gbln_value_new_str()`.
+///
+/// # Safety
+/// - `value` must be a valid null-terminated UTF-8 string
+/// - `out_value` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_value_new_str_checked(
+    value: *const c_char,
+    max_len: usize,
+    out_value: *mut *mut GblnValue,
+) -> GblnErrorCode {
+    if value.is_null() || out_value.is_null() {
+        set_last_error("Null pointer for string value".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let value_str = unsafe {
+        match CStr::from_ptr(value).to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8: {}", e), None);
+                return GblnErrorCode::ErrorInvalidSyntax;
+            }
+        }
+    };
+
+    let char_count = value_str.chars().count();
+    if char_count > max_len {
+        set_last_error(
+            format!("String too long: {} chars (max: {})", char_count, max_len),
+            Some(format!(
+                "Use a larger string type (s{} or larger)",
+                max_len * 2
+            )),
+        );
+        return GblnErrorCode::ErrorStringTooLong;
+    }
+
+    let mut owned = String::new();
+    if owned.try_reserve(value_str.len()).is_err() {
+        set_last_error("Failed to reserve capacity for string".to_string(), None);
+        return GblnErrorCode::ErrorAllocation;
+    }
+    owned.push_str(value_str);
+
+    match try_box_value(Value::Str(owned)) {
+        Some(boxed) => {
+            unsafe {
+                *out_value = Box::into_raw(boxed);
+            }
+            GblnErrorCode::Ok
+        }
+        None => {
+            set_last_error("Allocation failed".to_string(), None);
+            GblnErrorCode::ErrorAllocation
+        }
+    }
+}
+
+/// Create empty object, reporting allocation failure instead of aborting
+///
+/// # Safety
+/// - `out_value` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_value_new_object_checked(out_value: *mut *mut GblnValue) -> GblnErrorCode {
+    if out_value.is_null() {
+        set_last_error("Null out_value pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    match try_box_value(Value::Object(HashMap::new())) {
+        Some(boxed) => {
+            unsafe {
+                *out_value = Box::into_raw(boxed);
+            }
+            GblnErrorCode::Ok
+        }
+        None => {
+            set_last_error("Allocation failed".to_string(), None);
+            GblnErrorCode::ErrorAllocation
+        }
+    }
+}
+
+/// Create empty array, reporting allocation failure instead of aborting
+///
+/// # Safety
+/// - `out_value` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_value_new_array_checked(out_value: *mut *mut GblnValue) -> GblnErrorCode {
+    if out_value.is_null() {
+        set_last_error("Null out_value pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    match try_box_value(Value::Array(Vec::new())) {
+        Some(boxed) => {
+            unsafe {
+                *out_value = Box::into_raw(boxed);
+            }
+            GblnErrorCode::Ok
+        }
+        None => {
+            set_last_error("Allocation failed".to_string(), None);
+            GblnErrorCode::ErrorAllocation
+        }
+    }
+}
+
+// ============================================================================
+// Bulk Construction
+// ============================================================================
+//
+// Building a large object/array one `gbln_object_insert()`/`gbln_array_push()`
+// call at a time means one FFI crossing per field; for documents with
+// thousands of entries that per-call cost dominates. These entry points take
+// the whole entry list across the boundary once.
+
+/// Free the value pointers in `values[from..count]`, discarding their
+/// contents. Used to honour the "every value pointer is consumed in one
+/// call" contract of the bulk constructors even on an error path.
+unsafe fn drop_remaining_values(values: *mut *mut GblnValue, from: usize, count: usize) {
+    for i in from..count {
+        let value_ptr = *values.add(i);
+        if !value_ptr.is_null() {
+            drop(Box::from_raw(value_ptr));
+        }
+    }
+}
+
+/// Build an object from a batch of (key, value) entries in a single call
+///
+/// Takes ownership of every value pointer in `values[0..count]`, exactly as
+/// repeated `gbln_object_insert()` calls would, but pre-reserves the
+/// underlying `HashMap` with capacity `count` so the whole batch amortizes
+/// one FFI crossing instead of `count` of them.
+///
+/// Keys are read as counted, non-null-terminated buffers (`keys[i]`,
+/// `key_lens[i]`), tolerating interior NULs.
+///
+/// # Returns
+/// - GBLN_OK on success, with `out_value` set to the built object
+/// - GBLN_ERROR_DUPLICATE_KEY if two entries share a key
+/// - GBLN_ERROR_INVALID_SYNTAX if a key is not valid UTF-8
+/// - GBLN_ERROR_NULL_POINTER if any required pointer is NULL
+///
+/// On any error, every value pointer in `values[0..count]` is still freed
+/// (the already-inserted ones drop with the partially built map, the rest
+/// are freed directly) so the caller never leaks.
+///
+/// # Safety
+/// - `keys` and `key_lens` must each point to `count` valid entries
+/// - `values` must point to `count` valid `GblnValue` pointers
+/// - `out_value` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_object_from_entries(
+    keys: *const *const c_char,
+    key_lens: *const usize,
+    values: *mut *mut GblnValue,
+    count: usize,
+    out_value: *mut *mut GblnValue,
+) -> GblnErrorCode {
+    if out_value.is_null() {
+        set_last_error("Null out_value pointer".to_string(), None);
+        if !values.is_null() {
+            unsafe {
+                drop_remaining_values(values, 0, count);
+            }
+        }
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    if count > 0 && (keys.is_null() || key_lens.is_null() || values.is_null()) {
+        set_last_error("Null pointer in object_from_entries".to_string(), None);
+        if !values.is_null() {
+            unsafe {
+                drop_remaining_values(values, 0, count);
+            }
+        }
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let mut map = HashMap::with_capacity(count);
+
+    for i in 0..count {
+        let key_ptr = unsafe { *keys.add(i) } as *const u8;
+        let key_len = unsafe { *key_lens.add(i) };
+        let value_ptr = unsafe { *values.add(i) };
+
+        let key_bytes = if key_len == 0 {
+            &[][..]
+        } else {
+            unsafe { std::slice::from_raw_parts(key_ptr, key_len) }
+        };
+
+        let key_str = match std::str::from_utf8(key_bytes) {
+            Ok(s) => s.to_string(),
+            Err(e) => {
+                set_last_error(
+                    format!("Invalid UTF-8 in key at byte offset {}", e.valid_up_to()),
+                    None,
+                );
+                unsafe {
+                    drop_remaining_values(values, i, count);
+                }
+                return GblnErrorCode::ErrorInvalidSyntax;
+            }
+        };
+
+        if map.contains_key(&key_str) {
+            set_last_error(
+                format!("Duplicate key: {}", key_str),
+                Some("Use a different key name".to_string()),
+            );
+            unsafe {
+                drop_remaining_values(values, i, count);
+            }
+            return GblnErrorCode::ErrorDuplicateKey;
+        }
+
+        let value_inner = unsafe { Box::from_raw(value_ptr) }.into_inner();
+        map.insert(key_str, value_inner);
+    }
+
+    let boxed = Box::new(GblnValue::new(Value::Object(map)));
+    unsafe {
+        *out_value = Box::into_raw(boxed);
+    }
+    GblnErrorCode::Ok
+}
+
+/// Build an array from a batch of values in a single call
+///
+/// Takes ownership of every value pointer in `values[0..count]`, exactly as
+/// repeated `gbln_array_push()` calls would, but pre-reserves the underlying
+/// `Vec` with capacity `count` so the whole batch amortizes one FFI crossing
+/// instead of `count` of them.
+///
+/// # Safety
+/// - `values` must point to `count` valid `GblnValue` pointers
+/// - `out_value` must be a valid pointer to store the result
+#[no_mangle]
+pub extern "C" fn gbln_array_from_values(
+    values: *mut *mut GblnValue,
+    count: usize,
+    out_value: *mut *mut GblnValue,
+) -> GblnErrorCode {
+    if out_value.is_null() {
+        set_last_error("Null out_value pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    if count > 0 && values.is_null() {
+        set_last_error("Null values pointer in array_from_values".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let mut items = Vec::with_capacity(count);
+    for i in 0..count {
+        let value_ptr = unsafe { *values.add(i) };
+        let value_inner = unsafe { Box::from_raw(value_ptr) }.into_inner();
+        items.push(value_inner);
+    }
+
+    let boxed = Box::new(GblnValue::new(Value::Array(items)));
+    unsafe {
+        *out_value = Box::into_raw(boxed);
+    }
+    GblnErrorCode::Ok
+}
+
+// ============================================================================
+// Mutation
+// ============================================================================
+//
+// `gbln_object_insert`/`gbln_array_push` only grow a container. These
+// entry points let C mutate an already-built tree in place: replace a
+// field/element, or remove one outright.
+
+/// Insert or overwrite a field in an object
+///
+/// Unlike `gbln_object_insert()`, an existing field under `key` is replaced
+/// (and freed) instead of returning `GBLN_ERROR_DUPLICATE_KEY`.
+///
+/// # Safety
+/// - `object` must be a GblnValue of type Object
+/// - `key` must be a valid null-terminated UTF-8 string
+/// - `value` ownership is transferred to the object on success; on failure
+///   the caller retains ownership and must free it
+#[no_mangle]
+pub extern "C" fn gbln_object_set(
+    object: *mut GblnValue,
+    key: *const c_char,
+    value: *mut GblnValue,
+) -> GblnErrorCode {
+    if object.is_null() || key.is_null() || value.is_null() {
+        set_last_error("Null pointer in object_set".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let key_str = unsafe {
+        match CStr::from_ptr(key).to_str() {
+            Ok(s) => s.to_string(),
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in key: {}", e), None);
+                return GblnErrorCode::ErrorNullPointer;
+            }
+        }
+    };
+
+    let object_ref = unsafe { &mut *object };
+
+    match object_ref.inner() {
+        Value::Object(_) => {
+            let inner_ptr = object_ref as *mut GblnValue as *mut Value;
+            let inner_ref = unsafe { &mut *inner_ptr };
+
+            if let Value::Object(ref mut map) = inner_ref {
+                let value_inner = unsafe { Box::from_raw(value) }.into_inner();
+                // Dropping the previous value (if any) frees it.
+                map.insert(key_str, value_inner);
+                GblnErrorCode::Ok
+            } else {
+                unreachable!()
+            }
+        }
+        _ => {
+            set_last_error(
+                "Value is not an object".to_string(),
+                Some("Use gbln_value_new_object() to create an object".to_string()),
+            );
+            GblnErrorCode::ErrorTypeMismatch
+        }
+    }
+}
+
+/// Remove a field from an object
+///
+/// The removed value is dropped; it is not handed back to the caller.
+///
+/// # Returns
+/// - GBLN_OK if the field was removed, or if no field named `key` existed
+/// - GBLN_ERROR_TYPE_MISMATCH if `object` is not an Object type
+/// - GBLN_ERROR_NULL_POINTER if any pointer is null
+///
+/// # Safety
+/// - `object` must be a GblnValue of type Object
+/// - `key` must be a valid null-terminated UTF-8 string
+#[no_mangle]
+pub extern "C" fn gbln_object_remove(object: *mut GblnValue, key: *const c_char) -> GblnErrorCode {
+    if object.is_null() || key.is_null() {
+        set_last_error("Null pointer in object_remove".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let key_str = unsafe {
+        match CStr::from_ptr(key).to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in key: {}", e), None);
+                return GblnErrorCode::ErrorNullPointer;
+            }
+        }
+    };
+
+    let object_ref = unsafe { &mut *object };
+
+    match object_ref.inner() {
+        Value::Object(_) => {
+            let inner_ptr = object_ref as *mut GblnValue as *mut Value;
+            let inner_ref = unsafe { &mut *inner_ptr };
+
+            if let Value::Object(ref mut map) = inner_ref {
+                // Dropping the removed value (if any) frees it.
+                map.remove(key_str);
+                GblnErrorCode::Ok
+            } else {
+                unreachable!()
+            }
+        }
+        _ => {
+            set_last_error(
+                "Value is not an object".to_string(),
+                Some("Use gbln_value_new_object() to create an object".to_string()),
+            );
+            GblnErrorCode::ErrorTypeMismatch
+        }
+    }
+}
+
+/// Replace an array element in place
+///
+/// The previous element at `index` is dropped.
+///
+/// # Returns
+/// - GBLN_OK on success
+/// - GBLN_ERROR_TYPE_MISMATCH if `array` is not an Array type
+/// - GBLN_ERROR_INT_OUT_OF_RANGE if `index` is out of bounds
+/// - GBLN_ERROR_NULL_POINTER if any pointer is null
+///
+/// # Safety
+/// - `array` must be a GblnValue of type Array
+/// - `value` ownership is transferred to the array on success; on failure
+///   the caller retains ownership and must free it
+#[no_mangle]
+pub extern "C" fn gbln_array_set(
+    array: *mut GblnValue,
+    index: usize,
+    value: *mut GblnValue,
+) -> GblnErrorCode {
+    if array.is_null() || value.is_null() {
+        set_last_error("Null pointer in array_set".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let array_ref = unsafe { &mut *array };
+
+    match array_ref.inner() {
+        Value::Array(_) => {
+            let inner_ptr = array_ref as *mut GblnValue as *mut Value;
+            let inner_ref = unsafe { &mut *inner_ptr };
+
+            if let Value::Array(ref mut vec) = inner_ref {
+                if index >= vec.len() {
+                    set_last_error(format!("Index {} out of bounds", index), None);
+                    return GblnErrorCode::ErrorIntOutOfRange;
+                }
+
+                let value_inner = unsafe { Box::from_raw(value) }.into_inner();
+                vec[index] = value_inner;
+                GblnErrorCode::Ok
+            } else {
+                unreachable!()
+            }
+        }
+        _ => {
+            set_last_error(
+                "Value is not an array".to_string(),
+                Some("Use gbln_value_new_array() to create an array".to_string()),
+            );
+            GblnErrorCode::ErrorTypeMismatch
+        }
+    }
+}
+
+/// Remove an array element, shifting later elements down
+///
+/// # Returns
+/// - GBLN_OK on success
+/// - GBLN_ERROR_TYPE_MISMATCH if `array` is not an Array type
+/// - GBLN_ERROR_INT_OUT_OF_RANGE if `index` is out of bounds
+/// - GBLN_ERROR_NULL_POINTER if `array` is null
+///
+/// # Safety
+/// - `array` must be a GblnValue of type Array
+#[no_mangle]
+pub extern "C" fn gbln_array_remove(array: *mut GblnValue, index: usize) -> GblnErrorCode {
+    if array.is_null() {
+        set_last_error("Null pointer in array_remove".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let array_ref = unsafe { &mut *array };
+
+    match array_ref.inner() {
+        Value::Array(_) => {
+            let inner_ptr = array_ref as *mut GblnValue as *mut Value;
+            let inner_ref = unsafe { &mut *inner_ptr };
+
+            if let Value::Array(ref mut vec) = inner_ref {
+                if index >= vec.len() {
+                    set_last_error(format!("Index {} out of bounds", index), None);
+                    return GblnErrorCode::ErrorIntOutOfRange;
+                }
+
+                // Dropping the removed element frees it.
+                vec.remove(index);
+                GblnErrorCode::Ok
+            } else {
+                unreachable!()
+            }
+        }
+        _ => {
+            set_last_error(
+                "Value is not an array".to_string(),
+                Some("Use gbln_value_new_array() to create an array".to_string()),
+            );
+            GblnErrorCode::ErrorTypeMismatch
+        }
+    }
+}
@@ -0,0 +1,203 @@
+// Copyright (c) 2025 Vivian Burkhard Voss
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bounded, cycle-safe value pretty-printer
+//!
+//! `gbln_to_string_pretty()` always renders a value in full, which is fine
+//! for round-tripping but unsuitable for logging or debug-printing a value
+//! of unknown size or shape: a huge array prints unbounded output, and a
+//! value built up through the mutation API in `extensions.rs` could in
+//! principle reference the same container twice. `gbln_value_format()`
+//! instead renders with a depth limit, a per-container element limit, and
+//! cycle detection by node identity, so it is always safe to call on
+//! arbitrary input.
+
+use crate::types::GblnValue;
+use gbln::Value;
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// Options controlling `gbln_value_format()`'s output
+///
+/// A zero `max_depth`/`max_elements` means unlimited.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct GblnFormatOptions {
+    pub max_depth: usize,
+    pub max_elements: usize,
+    pub quote_strings: bool,
+    pub indent: usize,
+}
+
+impl Default for GblnFormatOptions {
+    fn default() -> Self {
+        GblnFormatOptions {
+            max_depth: 64,
+            max_elements: 100,
+            quote_strings: true,
+            indent: 2,
+        }
+    }
+}
+
+fn write_indent(out: &mut String, options: &GblnFormatOptions, depth: usize) {
+    if options.indent > 0 {
+        out.push('\n');
+        out.push_str(&" ".repeat(options.indent * depth));
+    }
+}
+
+fn write_string(out: &mut String, s: &str, quote: bool) {
+    if !quote {
+        out.push_str(s);
+        return;
+    }
+
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn format_node(
+    value: &Value,
+    options: &GblnFormatOptions,
+    depth: usize,
+    visiting: &mut HashSet<usize>,
+    out: &mut String,
+) {
+    if options.max_depth > 0 && depth > options.max_depth {
+        out.push_str("...");
+        return;
+    }
+
+    match value {
+        Value::Object(map) => {
+            let node_id = value as *const Value as usize;
+            if !visiting.insert(node_id) {
+                out.push_str("<cycle>");
+                return;
+            }
+
+            out.push('{');
+            let limit = if options.max_elements > 0 {
+                options.max_elements.min(map.len())
+            } else {
+                map.len()
+            };
+            for (i, (key, child)) in map.iter().enumerate() {
+                if i >= limit {
+                    write_indent(out, options, depth + 1);
+                    out.push_str(&format!("... ({} more)", map.len() - limit));
+                    break;
+                }
+                if i > 0 {
+                    out.push(',');
+                }
+                write_indent(out, options, depth + 1);
+                write_string(out, key, options.quote_strings);
+                out.push_str(": ");
+                format_node(child, options, depth + 1, visiting, out);
+            }
+            if !map.is_empty() {
+                write_indent(out, options, depth);
+            }
+            out.push('}');
+
+            visiting.remove(&node_id);
+        }
+        Value::Array(items) => {
+            let node_id = value as *const Value as usize;
+            if !visiting.insert(node_id) {
+                out.push_str("<cycle>");
+                return;
+            }
+
+            out.push('[');
+            let limit = if options.max_elements > 0 {
+                options.max_elements.min(items.len())
+            } else {
+                items.len()
+            };
+            for (i, child) in items.iter().enumerate() {
+                if i >= limit {
+                    write_indent(out, options, depth + 1);
+                    out.push_str(&format!("... ({} more)", items.len() - limit));
+                    break;
+                }
+                if i > 0 {
+                    out.push(',');
+                }
+                write_indent(out, options, depth + 1);
+                format_node(child, options, depth + 1, visiting, out);
+            }
+            if !items.is_empty() {
+                write_indent(out, options, depth);
+            }
+            out.push(']');
+
+            visiting.remove(&node_id);
+        }
+        Value::Str(s) => write_string(out, s, options.quote_strings),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Null => out.push_str("null"),
+        Value::I8(n) => out.push_str(&n.to_string()),
+        Value::I16(n) => out.push_str(&n.to_string()),
+        Value::I32(n) => out.push_str(&n.to_string()),
+        Value::I64(n) => out.push_str(&n.to_string()),
+        Value::U8(n) => out.push_str(&n.to_string()),
+        Value::U16(n) => out.push_str(&n.to_string()),
+        Value::U32(n) => out.push_str(&n.to_string()),
+        Value::U64(n) => out.push_str(&n.to_string()),
+        Value::F32(n) => out.push_str(&n.to_string()),
+        Value::F64(n) => out.push_str(&n.to_string()),
+    }
+}
+
+/// Render a `GblnValue` as a bounded, cycle-safe string for logging/debugging
+///
+/// Unlike `gbln_to_string_pretty()`, this elides whatever lies past the
+/// depth and element limits and never recurses into a node it is already
+/// inside: both are reported inline (`...` / `<cycle>`) rather than
+/// overflowing the caller's log. A limit set to zero is unlimited, so with
+/// both limits zero the output is not bounded.
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `options` may be NULL (uses sane defaults)
+/// - Caller must free the returned string with `gbln_string_free()`
+#[no_mangle]
+pub extern "C" fn gbln_value_format(
+    value: *const GblnValue,
+    options: *const GblnFormatOptions,
+) -> *mut c_char {
+    if value.is_null() {
+        return ptr::null_mut();
+    }
+
+    let options = if options.is_null() {
+        GblnFormatOptions::default()
+    } else {
+        unsafe { *options }
+    };
+
+    let mut out = String::new();
+    let mut visiting = HashSet::new();
+    format_node(unsafe { (*value).inner() }, &options, 0, &mut visiting, &mut out);
+
+    match CString::new(out) {
+        Ok(s) => s.into_raw(),
+        Err(_) => ptr::null_mut(),
+    }
+}
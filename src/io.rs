@@ -7,13 +7,95 @@
 //! GBLN I/O format files (.io.gbln.xz)
 
 use std::ffi::CStr;
+use std::io::{Cursor, Read, Write};
 use std::os::raw::c_char;
 use std::path::Path;
 
 use crate::config::GblnConfig;
 use crate::error::{set_last_error, GblnErrorCode};
 use crate::types::GblnValue;
-use gbln::{read_io as rust_read_io, write_io as rust_write_io};
+use gbln::{
+    parse, read_io as rust_read_io, to_string, to_string_pretty, write_io as rust_write_io, Value,
+};
+use xz2::read::XzDecoder;
+use xz2::write::XzEncoder;
+
+/// XZ stream magic bytes, used to auto-detect compressed input
+const XZ_MAGIC: [u8; 6] = [0xFD, 0x37, 0x7A, 0x58, 0x5A, 0x00];
+
+/// Serialise `value` per `config`, compressing with XZ when requested.
+///
+/// Shared by the buffer-oriented I/O entry points and
+/// `gbln_to_bytes_with_config()`.
+///
+/// Unlike `gbln_write_io()` (which hands the whole `GblnConfig` to
+/// `rust_write_io()`), this only honors `mini_mode` (selects
+/// `to_string`/`to_string_pretty`) and `compress`/`compression_level`
+/// (XZ). `config.indent` and `config.strip_comments` are accepted for ABI
+/// symmetry but ignored here - neither `to_string` nor `to_string_pretty`
+/// takes them, so a buffer produced via `gbln_to_bytes_with_config()` with
+/// a custom indent will not match what `gbln_write_io()` would have
+/// written for the same config. Use the file-based functions when those
+/// settings must be honored.
+pub(crate) fn encode_with_config(
+    value: &Value,
+    config: &gbln::GblnConfig,
+) -> Result<Vec<u8>, String> {
+    let text = if config.mini_mode {
+        to_string(value)
+    } else {
+        to_string_pretty(value)
+    };
+
+    if config.compress {
+        let mut encoder = XzEncoder::new(Vec::new(), config.compression_level as u32);
+        encoder
+            .write_all(text.as_bytes())
+            .map_err(|e| format!("XZ compression failed: {}", e))?;
+        encoder
+            .finish()
+            .map_err(|e| format!("XZ compression failed: {}", e))
+    } else {
+        Ok(text.into_bytes())
+    }
+}
+
+/// Parse `bytes` as GBLN, transparently decompressing XZ-magic-prefixed input.
+///
+/// Shared by the buffer-oriented I/O entry points and
+/// `gbln_from_bytes_with_config()`.
+pub(crate) fn decode_auto(bytes: &[u8]) -> Result<Value, (GblnErrorCode, String, Option<String>)> {
+    let decompressed;
+    let text_bytes: &[u8] = if bytes.starts_with(&XZ_MAGIC) {
+        let mut out = Vec::new();
+        XzDecoder::new(Cursor::new(bytes))
+            .read_to_end(&mut out)
+            .map_err(|e| {
+                (
+                    GblnErrorCode::ErrorIo,
+                    format!("XZ decompression failed: {}", e),
+                    None,
+                )
+            })?;
+        decompressed = out;
+        &decompressed
+    } else {
+        bytes
+    };
+
+    let text = std::str::from_utf8(text_bytes).map_err(|e| {
+        (
+            GblnErrorCode::ErrorInvalidSyntax,
+            format!("Invalid UTF-8: {}", e),
+            None,
+        )
+    })?;
+
+    parse(text).map_err(|e| {
+        let suggestion = e.suggestion.clone();
+        (crate::error::map_error_kind(&e.kind), e.to_string(), suggestion)
+    })
+}
 
 /// Write GBLN value to I/O format file
 ///
@@ -167,3 +249,116 @@ pub extern "C" fn gbln_read_io(
         }
     }
 }
+
+/// Read GBLN from a caller-supplied buffer
+///
+/// Equivalent to `gbln_read_io()`, but reads from an in-memory buffer
+/// instead of a filesystem path. Useful when the host mediates all I/O
+/// (sockets, memory-mapped regions, sandboxed/enclave deployments) and a
+/// temp file is not an option.
+///
+/// Applies the same XZ magic-byte auto-detection (`FD 37 7A 58 5A 00`) and
+/// decompression as `gbln_read_io()` before parsing.
+///
+/// # Safety
+/// - `data` must be valid for reads of `len` bytes
+/// - `out_value` must be a valid pointer to store the result
+/// - Caller must free the returned value with `gbln_value_free()`
+#[no_mangle]
+pub extern "C" fn gbln_read_io_buf(
+    data: *const u8,
+    len: usize,
+    out_value: *mut *mut GblnValue,
+) -> GblnErrorCode {
+    if (data.is_null() && len != 0) || out_value.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let bytes = if len == 0 {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(data, len) }
+    };
+
+    match decode_auto(bytes) {
+        Ok(value) => {
+            let boxed = Box::new(GblnValue::new(value));
+            unsafe {
+                *out_value = Box::into_raw(boxed);
+            }
+            GblnErrorCode::Ok
+        }
+        Err((code, message, suggestion)) => {
+            set_last_error(message, suggestion);
+            code
+        }
+    }
+}
+
+/// Serialise a GBLN value into a freshly allocated buffer
+///
+/// Like `gbln_write_io()`, but serialises into memory instead of a
+/// filesystem path. Only `mini_mode`, `compress`, and `compression_level`
+/// are honored; `indent`/`strip_comments` are ignored (see
+/// `encode_with_config()`), so the bytes can differ from what
+/// `gbln_write_io()` writes for the same config.
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `config` may be NULL (uses default `io_format()`)
+/// - `out_buf`/`out_len` must be valid pointers to store the result
+/// - Caller must free the returned buffer with `gbln_buffer_free()`
+#[no_mangle]
+pub extern "C" fn gbln_write_io_buf(
+    value: *const GblnValue,
+    config: *const GblnConfig,
+    out_buf: *mut *mut u8,
+    out_len: *mut usize,
+) -> GblnErrorCode {
+    if value.is_null() || out_buf.is_null() || out_len.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let rust_config = if config.is_null() {
+        gbln::GblnConfig::io_format()
+    } else {
+        unsafe { (*config).inner.clone() }
+    };
+
+    let rust_value = unsafe { (*value).inner() };
+    let bytes = match encode_with_config(rust_value, &rust_config) {
+        Ok(bytes) => bytes,
+        Err(message) => {
+            set_last_error(message, None);
+            return GblnErrorCode::ErrorIo;
+        }
+    };
+
+    let len = bytes.len();
+    let mut boxed = bytes.into_boxed_slice();
+    let ptr = boxed.as_mut_ptr();
+    std::mem::forget(boxed);
+
+    unsafe {
+        *out_buf = ptr;
+        *out_len = len;
+    }
+    GblnErrorCode::Ok
+}
+
+/// Free a buffer returned by `gbln_write_io_buf()`
+///
+/// # Safety
+/// - `ptr`/`len` must be exactly the pair returned from `gbln_write_io_buf()`
+/// - Must not be called twice on the same buffer
+#[no_mangle]
+pub extern "C" fn gbln_buffer_free(ptr: *mut u8, len: usize) {
+    if ptr.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Box::from_raw(std::slice::from_raw_parts_mut(ptr, len)));
+    }
+}
@@ -7,12 +7,26 @@ use std::os::raw::c_char;
 use std::ptr;
 
 mod accessors;
+mod config;
+mod diagnostics;
 mod error;
+mod buffer;
 mod extensions;
+mod format;
+mod io;
+mod packed;
+mod schema;
 mod types;
+mod visitor;
 
-pub use error::{get_last_error, set_last_error, GblnErrorCode};
+pub use buffer::GblnBuffer;
+pub use config::GblnConfig;
+pub use diagnostics::GblnDiagnostic;
+pub use error::{get_last_error, set_last_error, GblnError, GblnErrorCode};
+pub use format::GblnFormatOptions;
+pub use packed::GblnPackedValue;
 pub use types::{GblnValue, GblnValueType};
+pub use visitor::GblnVisitor;
 
 /// Parse GBLN string into a value
 ///
@@ -60,6 +74,132 @@ pub extern "C" fn gbln_parse(
     }
 }
 
+/// Parse GBLN from a raw, length-delimited buffer
+///
+/// Equivalent to `gbln_parse()`, but reads `data[0..len]` directly instead of
+/// scanning for a NUL terminator, so callers can parse a slice of a larger
+/// mmap'd file or network buffer without copying it into a C string first.
+///
+/// # Safety
+/// - `data` must be valid for reads of `len` bytes
+/// - `out_value` must be a valid pointer to store the result
+/// - Caller must free the returned value with `gbln_value_free()`
+///
+/// # Returns
+/// - `GBLN_OK` on success, with `out_value` set to the parsed value
+/// - Error code on failure, with error details available via `gbln_last_error_message()`
+#[no_mangle]
+pub extern "C" fn gbln_parse_buf(
+    data: *const u8,
+    len: usize,
+    out_value: *mut *mut GblnValue,
+) -> GblnErrorCode {
+    if (data.is_null() && len != 0) || out_value.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let bytes = if len == 0 {
+        &[][..]
+    } else {
+        unsafe { std::slice::from_raw_parts(data, len) }
+    };
+
+    let input_str = match std::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(e) => {
+            set_last_error(
+                format!("Invalid UTF-8 at byte offset {}", e.valid_up_to()),
+                None,
+            );
+            return GblnErrorCode::ErrorInvalidSyntax;
+        }
+    };
+
+    match parse(input_str) {
+        Ok(value) => {
+            let boxed = Box::new(GblnValue::new(value));
+            unsafe {
+                *out_value = Box::into_raw(boxed);
+            }
+            GblnErrorCode::Ok
+        }
+        Err(e) => {
+            let suggestion = e.suggestion.clone();
+            set_last_error(e.to_string(), suggestion);
+            error::map_error_kind(&e.kind)
+        }
+    }
+}
+
+/// Parse GBLN string into a value, reporting errors independent of thread
+///
+/// Equivalent to `gbln_parse()`, but additionally writes a structured,
+/// caller-owned `GblnError` to `*out_error` on failure instead of relying
+/// solely on the thread-local `gbln_last_error_message()` slot. Useful for
+/// hosts that dispatch parsing onto a thread pool, where the thread that
+/// calls `gbln_parse_ex()` may not be the thread that later inspects the
+/// error.
+///
+/// # Safety
+/// - `input` must be a valid null-terminated UTF-8 string
+/// - `out_value` must be a valid pointer to store the result
+/// - `out_error` may be NULL (the thread-local slot is still populated);
+///   if non-NULL, a successful call leaves `*out_error` untouched and the
+///   caller must free a reported error with `gbln_error_free()`
+/// - Caller must free the returned value with `gbln_value_free()`
+#[no_mangle]
+pub extern "C" fn gbln_parse_ex(
+    input: *const c_char,
+    out_value: *mut *mut GblnValue,
+    out_error: *mut *mut error::GblnError,
+) -> GblnErrorCode {
+    if input.is_null() || out_value.is_null() {
+        return error::report_error(
+            out_error,
+            GblnErrorCode::ErrorNullPointer,
+            "Null pointer".to_string(),
+            None,
+            1,
+            1,
+            0,
+        );
+    }
+
+    let input_str = match unsafe { CStr::from_ptr(input).to_str() } {
+        Ok(s) => s,
+        Err(e) => {
+            return error::report_error(
+                out_error,
+                GblnErrorCode::ErrorNullPointer,
+                format!("Invalid UTF-8: {}", e),
+                None,
+                1,
+                1,
+                0,
+            );
+        }
+    };
+
+    match parse(input_str) {
+        Ok(value) => {
+            let boxed = Box::new(GblnValue::new(value));
+            unsafe {
+                *out_value = Box::into_raw(boxed);
+            }
+            GblnErrorCode::Ok
+        }
+        Err(e) => {
+            let code = error::map_error_kind(&e.kind);
+            let suggestion = e.suggestion.clone();
+            // gbln::Error exposes no byte offset for the failing token (see
+            // the caveat on GblnError), so line/column/byte_offset default
+            // to the start of the document rather than the actual failure.
+            error::report_error(out_error, code, e.to_string(), suggestion, 1, 1, 0)
+        }
+    }
+}
+
 /// Free a GBLN value
 ///
 /// # Safety
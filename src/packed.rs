@@ -0,0 +1,318 @@
+// Copyright (c) 2025 Vivian Burkhard Voss
+// SPDX-License-Identifier: Apache-2.0
+
+//! NaN-tagged 8-byte scalar value for zero-allocation FFI passing
+//!
+//! Every scalar crossing the boundary via `GblnValue` is boxed behind an
+//! opaque pointer, which means a heap allocation and a free for even a
+//! single `i32` or `bool`. `GblnPackedValue` is a plain `u64` passed by
+//! value in the C ABI instead, using NaN-tagging so scalars that fit never
+//! allocate:
+//!
+//! - Any `f64` that is not a quiet NaN is stored as its own bit pattern -
+//!   it *is* the double.
+//! - The quiet-NaN space (exponent bits all 1, top mantissa bit set) is
+//!   repurposed: a 3-bit tag in the next mantissa bits selects `Null`,
+//!   `Bool`, `I32`, `U32`, or `Ptr`, with the payload in the low 32 (or,
+//!   for `Ptr`, low 48) bits.
+//! - `I64`/`U64`/`F32`/`Str`/`Object`/`Array`, any integer exceeding 32
+//!   bits, `I8`/`I16`/`U8`/`U16` (which have no tag of their own), and the
+//!   rare `F64` that is itself a quiet NaN are not stored inline; those
+//!   fall back to the `Ptr` tag pointing at the original `GblnValue`, which
+//!   the caller must still free via `gbln_value_free()` as usual.
+
+use crate::types::{GblnValue, GblnValueType};
+use gbln::Value;
+
+const EXP_MASK: u64 = 0x7FF << 52;
+const QUIET_BIT: u64 = 1 << 51;
+const TAG_SHIFT: u64 = 48;
+const TAG_MASK: u64 = 0b111 << TAG_SHIFT;
+const PAYLOAD_MASK: u64 = (1u64 << 48) - 1;
+
+const TAG_NULL: u64 = 0;
+const TAG_BOOL: u64 = 1;
+const TAG_I32: u64 = 2;
+const TAG_U32: u64 = 3;
+const TAG_PTR: u64 = 4;
+
+/// Plain `u64` scalar, NaN-tagged so it can be passed by value with no heap
+/// allocation.
+///
+/// # Safety
+/// A `Ptr`-tagged packed value borrows its payload's `GblnValue` from
+/// wherever it was packed from; it does not extend that pointer's
+/// lifetime, and freeing the original `GblnValue` invalidates it.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct GblnPackedValue(pub u64);
+
+fn is_tagged(bits: u64) -> bool {
+    (bits & EXP_MASK) == EXP_MASK && (bits & QUIET_BIT) != 0
+}
+
+fn pack_tag(tag: u64, payload: u64) -> GblnPackedValue {
+    GblnPackedValue(EXP_MASK | QUIET_BIT | (tag << TAG_SHIFT) | (payload & PAYLOAD_MASK))
+}
+
+fn pack_ptr(value: &Value) -> GblnPackedValue {
+    let ptr = value as *const Value as u64;
+    pack_tag(TAG_PTR, ptr)
+}
+
+/// Pack a `GblnValue` into a `GblnPackedValue`
+///
+/// The source `GblnValue` is unaffected (this does not consume or free it);
+/// for types that do not fit, the packed value simply points back at it, so
+/// the caller must still free the original with `gbln_value_free()`.
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+#[no_mangle]
+pub extern "C" fn gbln_value_pack(value: *const GblnValue) -> GblnPackedValue {
+    if value.is_null() {
+        return pack_tag(TAG_NULL, 0);
+    }
+
+    let inner = unsafe { (*value).inner() };
+    match inner {
+        Value::Null => pack_tag(TAG_NULL, 0),
+        Value::Bool(b) => pack_tag(TAG_BOOL, *b as u64),
+        Value::I32(n) => pack_tag(TAG_I32, *n as u32 as u64),
+        Value::U32(n) => pack_tag(TAG_U32, *n as u64),
+        Value::F64(n) => {
+            let bits = n.to_bits();
+            if is_tagged(bits) {
+                // This particular f64 is itself a quiet NaN and collides
+                // with the tagged space; fall back to a pointer so its
+                // exact bit pattern is preserved.
+                pack_ptr(inner)
+            } else {
+                GblnPackedValue(bits)
+            }
+        }
+        // I64/U64/F32/Str/Object/Array, and any value that didn't match
+        // above, don't fit in 48 bits (or were never given a tag) - fall
+        // back to a pointer.
+        _ => pack_ptr(inner),
+    }
+}
+
+/// Get the `GblnValueType` represented by a packed value
+///
+/// For a `Ptr`-tagged value this dereferences the pointer to report the
+/// real underlying type, so callers never need to know the packing scheme
+/// to type-switch on a `GblnPackedValue`.
+#[no_mangle]
+pub extern "C" fn gbln_packed_type(packed: GblnPackedValue) -> GblnValueType {
+    let bits = packed.0;
+    if !is_tagged(bits) {
+        return GblnValueType::F64;
+    }
+
+    match (bits & TAG_MASK) >> TAG_SHIFT {
+        TAG_NULL => GblnValueType::Null,
+        TAG_BOOL => GblnValueType::Bool,
+        TAG_I32 => GblnValueType::I32,
+        TAG_U32 => GblnValueType::U32,
+        TAG_PTR => {
+            let ptr = (bits & PAYLOAD_MASK) as *const Value;
+            if ptr.is_null() {
+                GblnValueType::Null
+            } else {
+                GblnValueType::from(unsafe { &*ptr })
+            }
+        }
+        _ => GblnValueType::Null,
+    }
+}
+
+/// Unpack a `Bool`-tagged value
+///
+/// # Safety
+/// - `ok` must be a valid pointer; set to true only if `packed` is `Bool`
+#[no_mangle]
+pub extern "C" fn gbln_packed_as_bool(packed: GblnPackedValue, ok: *mut bool) -> bool {
+    let bits = packed.0;
+    let matches = is_tagged(bits) && (bits & TAG_MASK) >> TAG_SHIFT == TAG_BOOL;
+    if !ok.is_null() {
+        unsafe {
+            *ok = matches;
+        }
+    }
+    matches && (bits & PAYLOAD_MASK) != 0
+}
+
+/// Unpack an `I32`-tagged value
+///
+/// # Safety
+/// - `ok` must be a valid pointer; set to true only if `packed` is `I32`
+#[no_mangle]
+pub extern "C" fn gbln_packed_as_i32(packed: GblnPackedValue, ok: *mut bool) -> i32 {
+    let bits = packed.0;
+    let matches = is_tagged(bits) && (bits & TAG_MASK) >> TAG_SHIFT == TAG_I32;
+    if !ok.is_null() {
+        unsafe {
+            *ok = matches;
+        }
+    }
+    if matches {
+        (bits & PAYLOAD_MASK) as u32 as i32
+    } else {
+        0
+    }
+}
+
+/// Unpack a `U32`-tagged value
+///
+/// # Safety
+/// - `ok` must be a valid pointer; set to true only if `packed` is `U32`
+#[no_mangle]
+pub extern "C" fn gbln_packed_as_u32(packed: GblnPackedValue, ok: *mut bool) -> u32 {
+    let bits = packed.0;
+    let matches = is_tagged(bits) && (bits & TAG_MASK) >> TAG_SHIFT == TAG_U32;
+    if !ok.is_null() {
+        unsafe {
+            *ok = matches;
+        }
+    }
+    if matches {
+        (bits & PAYLOAD_MASK) as u32
+    } else {
+        0
+    }
+}
+
+/// Unpack an untagged `F64` value
+///
+/// # Safety
+/// - `ok` must be a valid pointer; set to true only if `packed` is a plain `F64`
+#[no_mangle]
+pub extern "C" fn gbln_packed_as_f64(packed: GblnPackedValue, ok: *mut bool) -> f64 {
+    let matches = !is_tagged(packed.0);
+    if !ok.is_null() {
+        unsafe {
+            *ok = matches;
+        }
+    }
+    if matches {
+        f64::from_bits(packed.0)
+    } else {
+        0.0
+    }
+}
+
+/// Unpack a `Ptr`-tagged value back to the `GblnValue` it points at
+///
+/// # Safety
+/// - `ok` must be a valid pointer; set to true only if `packed` is `Ptr`
+/// - The returned pointer borrows from wherever `packed` was packed from;
+///   it is valid only as long as that `GblnValue` is
+#[no_mangle]
+pub extern "C" fn gbln_packed_as_ptr(packed: GblnPackedValue, ok: *mut bool) -> *const GblnValue {
+    let bits = packed.0;
+    let matches = is_tagged(bits) && (bits & TAG_MASK) >> TAG_SHIFT == TAG_PTR;
+    if !ok.is_null() {
+        unsafe {
+            *ok = matches;
+        }
+    }
+    if matches {
+        (bits & PAYLOAD_MASK) as *const GblnValue
+    } else {
+        std::ptr::null()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack(value: Value) -> GblnPackedValue {
+        gbln_value_pack(&GblnValue::new(value) as *const GblnValue)
+    }
+
+    #[test]
+    fn round_trips_null() {
+        let packed = pack(Value::Null);
+        assert_eq!(gbln_packed_type(packed), GblnValueType::Null);
+    }
+
+    #[test]
+    fn round_trips_bool() {
+        let mut ok = false;
+        let packed = pack(Value::Bool(true));
+        assert_eq!(gbln_packed_type(packed), GblnValueType::Bool);
+        assert!(gbln_packed_as_bool(packed, &mut ok));
+        assert!(ok);
+
+        let packed = pack(Value::Bool(false));
+        assert!(!gbln_packed_as_bool(packed, &mut ok));
+        assert!(ok);
+    }
+
+    #[test]
+    fn round_trips_i32() {
+        let mut ok = false;
+        let packed = pack(Value::I32(-42));
+        assert_eq!(gbln_packed_type(packed), GblnValueType::I32);
+        assert_eq!(gbln_packed_as_i32(packed, &mut ok), -42);
+        assert!(ok);
+    }
+
+    #[test]
+    fn round_trips_u32() {
+        let mut ok = false;
+        let packed = pack(Value::U32(42));
+        assert_eq!(gbln_packed_type(packed), GblnValueType::U32);
+        assert_eq!(gbln_packed_as_u32(packed, &mut ok), 42);
+        assert!(ok);
+    }
+
+    #[test]
+    fn round_trips_plain_f64() {
+        let mut ok = false;
+        let packed = pack(Value::F64(3.5));
+        assert_eq!(gbln_packed_type(packed), GblnValueType::F64);
+        assert_eq!(gbln_packed_as_f64(packed, &mut ok), 3.5);
+        assert!(ok);
+    }
+
+    #[test]
+    fn falls_back_to_ptr_for_types_that_dont_fit() {
+        let boxed = GblnValue::new(Value::I64(1234567890123));
+        let packed = gbln_value_pack(&boxed as *const GblnValue);
+        let mut ok = false;
+        assert_eq!(gbln_packed_type(packed), GblnValueType::I64);
+        let ptr = gbln_packed_as_ptr(packed, &mut ok);
+        assert!(ok);
+        assert!(!ptr.is_null());
+        match unsafe { (*ptr).inner() } {
+            Value::I64(n) => assert_eq!(*n, 1234567890123),
+            other => panic!("expected I64, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn falls_back_to_ptr_for_quiet_nan_f64() {
+        // A quiet NaN collides with the tagged space, so it must round-trip
+        // through the Ptr fallback instead of being stored inline.
+        let boxed = GblnValue::new(Value::F64(f64::NAN));
+        let packed = gbln_value_pack(&boxed as *const GblnValue);
+        let mut ok = false;
+        assert_eq!(gbln_packed_type(packed), GblnValueType::F64);
+        let ptr = gbln_packed_as_ptr(packed, &mut ok);
+        assert!(ok);
+        assert!(!ptr.is_null());
+        match unsafe { (*ptr).inner() } {
+            Value::F64(n) => assert!(n.is_nan()),
+            other => panic!("expected F64, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn null_pointer_packs_as_null() {
+        let packed = gbln_value_pack(std::ptr::null());
+        assert_eq!(gbln_packed_type(packed), GblnValueType::Null);
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright (c) 2025 Vivian Burkhard Voss
+// SPDX-License-Identifier: Apache-2.0
+
+//! Binary schema/typelib descriptor
+//!
+//! Generates a flat, ordered table of every field path in a value's object
+//! tree paired with its `GblnValueType`, so a host can build a typelib or
+//! validate shape without walking the value itself. Nested objects are
+//! addressed with dot-joined paths (`a.b.c`); array elements are addressed
+//! with a bracketed index appended to their parent's path (`items[0]`).
+//!
+//! The table is encoded into a `GblnBuffer` (the same struct `buffer.rs`
+//! uses) as a sequence of `(path_len: u32 LE, path bytes, type_tag: u8)`
+//! entries, with no leading count - `gbln_schema_field_count()` re-derives
+//! it by scanning, keeping the format self-describing.
+
+use crate::buffer::GblnBuffer;
+use crate::error::{set_last_error, GblnErrorCode};
+use crate::types::{GblnValue, GblnValueType};
+use gbln::Value;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
+
+fn walk(value: &Value, path: &str, out: &mut Vec<(String, GblnValueType)>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter() {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                out.push((child_path.clone(), GblnValueType::from(child)));
+                walk(child, &child_path, out);
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                let child_path = format!("{}[{}]", path, index);
+                out.push((child_path.clone(), GblnValueType::from(child)));
+                walk(child, &child_path, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn encode(entries: &[(String, GblnValueType)]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for (path, ty) in entries {
+        let path_bytes = path.as_bytes();
+        bytes.extend_from_slice(&(path_bytes.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(path_bytes);
+        bytes.push(*ty as u8);
+    }
+    bytes
+}
+
+fn decode(data: &[u8]) -> Vec<(String, GblnValueType)> {
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    while offset + 4 <= data.len() {
+        let path_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+        if offset + path_len + 1 > data.len() {
+            break;
+        }
+        let path = match std::str::from_utf8(&data[offset..offset + path_len]) {
+            Ok(s) => s.to_string(),
+            Err(_) => break,
+        };
+        offset += path_len;
+        let ty = type_from_tag(data[offset]);
+        offset += 1;
+        entries.push((path, ty));
+    }
+    entries
+}
+
+fn type_from_tag(tag: u8) -> GblnValueType {
+    match tag {
+        0 => GblnValueType::I8,
+        1 => GblnValueType::I16,
+        2 => GblnValueType::I32,
+        3 => GblnValueType::I64,
+        4 => GblnValueType::U8,
+        5 => GblnValueType::U16,
+        6 => GblnValueType::U32,
+        7 => GblnValueType::U64,
+        8 => GblnValueType::F32,
+        9 => GblnValueType::F64,
+        10 => GblnValueType::Str,
+        11 => GblnValueType::Bool,
+        13 => GblnValueType::Object,
+        14 => GblnValueType::Array,
+        _ => GblnValueType::Null,
+    }
+}
+
+/// Describe a value's recursive field schema into a `GblnBuffer`
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `out_buf` must be a valid pointer to store the result
+/// - Caller must free the result with `gbln_value_buffer_free()`
+#[no_mangle]
+pub extern "C" fn gbln_value_describe_schema(
+    value: *const GblnValue,
+    out_buf: *mut GblnBuffer,
+) -> GblnErrorCode {
+    if value.is_null() || out_buf.is_null() {
+        set_last_error("Null pointer".to_string(), None);
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let mut entries = Vec::new();
+    walk(unsafe { (*value).inner() }, "", &mut entries);
+
+    let mut bytes = encode(&entries);
+    let data = bytes.as_mut_ptr();
+    let len = bytes.len();
+    let capacity = bytes.capacity();
+    std::mem::forget(bytes);
+
+    unsafe {
+        *out_buf = GblnBuffer {
+            data,
+            len,
+            capacity,
+        };
+    }
+    GblnErrorCode::Ok
+}
+
+/// Count the fields described in a schema buffer
+///
+/// # Safety
+/// - `data` must be valid for reads of `len` bytes, as produced by
+///   `gbln_value_describe_schema()`
+#[no_mangle]
+pub extern "C" fn gbln_schema_field_count(data: *const u8, len: usize) -> usize {
+    if data.is_null() {
+        return 0;
+    }
+    let bytes = unsafe { std::slice::from_raw_parts(data, len) };
+    decode(bytes).len()
+}
+
+/// Get the field path at `index` in a schema buffer
+///
+/// # Safety
+/// - `data` must be valid for reads of `len` bytes, as produced by
+///   `gbln_value_describe_schema()`
+/// - Returns NULL if `index` is out of bounds
+/// - Caller must free the returned string with `gbln_string_free()`
+#[no_mangle]
+pub extern "C" fn gbln_schema_field_name(data: *const u8, len: usize, index: usize) -> *mut c_char {
+    if data.is_null() {
+        return ptr::null_mut();
+    }
+    let bytes = unsafe { std::slice::from_raw_parts(data, len) };
+    match decode(bytes).get(index) {
+        Some((path, _)) => CString::new(path.as_str())
+            .map(CString::into_raw)
+            .unwrap_or(ptr::null_mut()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Get the field type at `index` in a schema buffer
+///
+/// # Safety
+/// - `data` must be valid for reads of `len` bytes, as produced by
+///   `gbln_value_describe_schema()`
+/// - Returns `GblnValueType::Null` if `index` is out of bounds
+#[no_mangle]
+pub extern "C" fn gbln_schema_field_type(data: *const u8, len: usize, index: usize) -> GblnValueType {
+    if data.is_null() {
+        return GblnValueType::Null;
+    }
+    let bytes = unsafe { std::slice::from_raw_parts(data, len) };
+    match decode(bytes).get(index) {
+        Some((_, ty)) => *ty,
+        None => GblnValueType::Null,
+    }
+}
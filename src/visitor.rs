@@ -0,0 +1,154 @@
+// Copyright (c) 2025 Vivian Burkhard Voss
+// SPDX-License-Identifier: Apache-2.0
+
+//! Callback-based depth-first value visitor
+//!
+//! `gbln_object_keys()` and friends materialize an entire intermediate
+//! buffer just so a binding can walk a value once, and there is no way to
+//! traverse nested arrays/objects without repeatedly re-entering the FFI
+//! per node. `gbln_value_visit()` instead drives the traversal entirely on
+//! the Rust side and calls back into C at each node, so bindings can stream
+//! very large documents with zero intermediate allocation.
+
+use crate::error::GblnErrorCode;
+use crate::types::{GblnValue, GblnValueType};
+use gbln::Value;
+use std::os::raw::c_void;
+
+/// Table of traversal callbacks for `gbln_value_visit()`
+///
+/// Every field is optional; a NULL hook simply means "do nothing extra at
+/// this node", traversal still descends into any children. Any hook may
+/// return a code other than `GBLN_OK` to abort the traversal early; that
+/// code is propagated back as `gbln_value_visit()`'s return value.
+///
+/// # Safety
+/// Pointers passed to a hook (e.g. the key buffer in `on_object_key`) are
+/// borrowed from the value tree and are valid only for the duration of
+/// that call.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct GblnVisitor {
+    pub on_object_begin: Option<extern "C" fn(len: usize, user_data: *mut c_void) -> GblnErrorCode>,
+    pub on_object_key: Option<
+        extern "C" fn(key_ptr: *const u8, key_len: usize, user_data: *mut c_void) -> GblnErrorCode,
+    >,
+    pub on_object_end: Option<extern "C" fn(user_data: *mut c_void) -> GblnErrorCode>,
+    pub on_array_begin: Option<extern "C" fn(len: usize, user_data: *mut c_void) -> GblnErrorCode>,
+    pub on_array_index: Option<extern "C" fn(index: usize, user_data: *mut c_void) -> GblnErrorCode>,
+    pub on_array_end: Option<extern "C" fn(user_data: *mut c_void) -> GblnErrorCode>,
+    pub on_scalar: Option<
+        extern "C" fn(ty: GblnValueType, value: *const GblnValue, user_data: *mut c_void) -> GblnErrorCode,
+    >,
+}
+
+/// View a borrowed `&gbln::Value` as a `*const GblnValue` for a callback.
+///
+/// `GblnValue` is a transparent single-field wrapper around `Value` (the
+/// same trick `gbln_object_get()`/`gbln_array_get()` use), so this cast is
+/// sound as long as that layout holds.
+fn as_gbln_value(value: &Value) -> *const GblnValue {
+    value as *const Value as *const GblnValue
+}
+
+fn visit_node(value: &Value, callbacks: &GblnVisitor, user_data: *mut c_void) -> GblnErrorCode {
+    match value {
+        Value::Object(map) => {
+            if let Some(hook) = callbacks.on_object_begin {
+                let code = hook(map.len(), user_data);
+                if code != GblnErrorCode::Ok {
+                    return code;
+                }
+            }
+
+            for (key, child) in map.iter() {
+                if let Some(hook) = callbacks.on_object_key {
+                    let code = hook(key.as_ptr(), key.len(), user_data);
+                    if code != GblnErrorCode::Ok {
+                        return code;
+                    }
+                }
+
+                let code = visit_node(child, callbacks, user_data);
+                if code != GblnErrorCode::Ok {
+                    return code;
+                }
+            }
+
+            if let Some(hook) = callbacks.on_object_end {
+                let code = hook(user_data);
+                if code != GblnErrorCode::Ok {
+                    return code;
+                }
+            }
+
+            GblnErrorCode::Ok
+        }
+        Value::Array(items) => {
+            if let Some(hook) = callbacks.on_array_begin {
+                let code = hook(items.len(), user_data);
+                if code != GblnErrorCode::Ok {
+                    return code;
+                }
+            }
+
+            for (index, child) in items.iter().enumerate() {
+                if let Some(hook) = callbacks.on_array_index {
+                    let code = hook(index, user_data);
+                    if code != GblnErrorCode::Ok {
+                        return code;
+                    }
+                }
+
+                let code = visit_node(child, callbacks, user_data);
+                if code != GblnErrorCode::Ok {
+                    return code;
+                }
+            }
+
+            if let Some(hook) = callbacks.on_array_end {
+                let code = hook(user_data);
+                if code != GblnErrorCode::Ok {
+                    return code;
+                }
+            }
+
+            GblnErrorCode::Ok
+        }
+        scalar => {
+            if let Some(hook) = callbacks.on_scalar {
+                hook(GblnValueType::from(scalar), as_gbln_value(scalar), user_data)
+            } else {
+                GblnErrorCode::Ok
+            }
+        }
+    }
+}
+
+/// Depth-first traverse a `GblnValue`, invoking `callbacks` at each node
+///
+/// Objects report `on_object_begin`, then `on_object_key`/child for each
+/// field in turn, then `on_object_end`; arrays report `on_array_begin`, then
+/// `on_array_index`/element for each element in index order, then
+/// `on_array_end`; everything else is a scalar reported via `on_scalar`.
+/// Object fields are visited in the underlying `HashMap`'s iteration order,
+/// which is not the order the fields appeared in the source document.
+///
+/// # Safety
+/// - `value` must be a valid GblnValue pointer
+/// - `callbacks` must be a valid pointer to a `GblnVisitor`
+/// - `user_data` is passed through to every hook unchanged and may be NULL
+#[no_mangle]
+pub extern "C" fn gbln_value_visit(
+    value: *const GblnValue,
+    callbacks: *const GblnVisitor,
+    user_data: *mut c_void,
+) -> GblnErrorCode {
+    if value.is_null() || callbacks.is_null() {
+        return GblnErrorCode::ErrorNullPointer;
+    }
+
+    let value_ref = unsafe { (*value).inner() };
+    let callbacks_ref = unsafe { &*callbacks };
+    visit_node(value_ref, callbacks_ref, user_data)
+}